@@ -11,6 +11,8 @@ use ufmt::uwrite;
 use ufmt::Formatter;
 
 use super::time;
+#[cfg(feature = "instrument")]
+use avr_progmem::raw::instrument;
 
 
 pub struct Fraction {
@@ -58,6 +60,9 @@ impl uDisplay for Fraction {
 pub struct Stats {
 	duration_um: u32,
 	counts: u32,
+	// The progmem traffic of all iterations
+	#[cfg(feature = "instrument")]
+	flash: instrument::Counters,
 }
 impl uDisplay for Stats {
 	fn fmt<W: ?Sized>(&self, fmt: &mut Formatter<W>) -> Result<(), W::Error>
@@ -73,7 +78,23 @@ impl uDisplay for Stats {
 			},
 			self.duration_um / 1_000,
 			self.counts
-		)
+		)?;
+
+		#[cfg(feature = "instrument")]
+		uwrite!(
+			fmt,
+			", flash: {} reads/i, {} B/i",
+			Fraction {
+				nom: self.flash.reads,
+				den: self.counts
+			},
+			Fraction {
+				nom: self.flash.bytes,
+				den: self.counts
+			},
+		)?;
+
+		Ok(())
 	}
 }
 
@@ -112,6 +133,10 @@ where
 
 		//uwrite!(&mut self.test_writer, "Benchmarking count: {}", counts);
 
+		// Only count the progmem traffic of the actual benchmark
+		#[cfg(feature = "instrument")]
+		instrument::reset();
+
 		let start = self.clock.micros();
 		for _ in 0..counts {
 			f(&mut self.test_writer)
@@ -125,6 +150,8 @@ where
 		Stats {
 			duration_um: diff,
 			counts,
+			#[cfg(feature = "instrument")]
+			flash: instrument::total(),
 		}
 	}
 }
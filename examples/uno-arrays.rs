@@ -7,6 +7,8 @@
 // Define no_std only for AVR
 #![cfg_attr(target_arch = "avr", no_std)]
 #![cfg_attr(target_arch = "avr", no_main)]
+// Required by the "elpm" crate feature of avr-progmem
+#![cfg_attr(target_arch = "avr", feature(asm_experimental_arch))]
 
 
 use avr_progmem::progmem; // The macro
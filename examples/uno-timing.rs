@@ -5,6 +5,8 @@
 #![feature(const_option)]
 #![feature(test)]
 #![feature(bench_black_box)]
+// Required by the "elpm" crate feature of avr-progmem
+#![cfg_attr(target_arch = "avr", feature(asm_experimental_arch))]
 
 
 
@@ -0,0 +1,877 @@
+//! Best-effort safe wrapper for EEPROM.
+//!
+//! Besides the program memory and the data memory, AVR chips have a third
+//! memory domain: the EEPROM, a small non-volatile memory, which is typically
+//! used to store settings that shall survive a power cycle.
+//!
+//! Like the program memory, the EEPROM can not be accessed via normal pointer
+//! dereferencing, instead special I/O registers must be used.
+//! This module offers the [`EeMem`] struct that wraps pointers into the
+//! EEPROM, and gives access to the value via methods that [`load`]
+//! and [`store`] the value, analog to the [`ProgMem`] wrapper for the
+//! program memory.
+//!
+//! As convenience, the [`eeprom!`] macro is offered that will create
+//! a `static` in the EEPROM with the given value and wrap a pointer to it in
+//! the [`EeMem`] struct for you.
+//!
+//! ```rust
+//! use avr_progmem::eeprom;
+//!
+//! eeprom!{
+//!     /// Some setting stored in the EEPROM
+//!     static eeprom BRIGHTNESS: u8 = 128;
+//! }
+//!
+//! assert_eq!(128, BRIGHTNESS.load());
+//! BRIGHTNESS.store(42);
+//! assert_eq!(42, BRIGHTNESS.load());
+//! ```
+//!
+//! Notice, that the initial value given in the `eeprom` macro is put into
+//! the `.eeprom` section of your binary, which needs to be flashed onto
+//! the EEPROM separately (e.g. via the `.eep` file), otherwise the EEPROM
+//! will just contain whatever has been there before.
+//!
+//! On all other architectures than AVR, the EEPROM is simulated by normal
+//! data memory, allowing to test code using the EEPROM on your host.
+//! All accesses to the simulated EEPROM are serialized by a global lock, thus
+//! it may be used from multiple threads, e.g. by concurrent tests.
+//!
+//! On AVR, the EEPROM is accessed via the EECR, EEDR, EEARL, and EEARH I/O
+//! registers, whose addresses differ between the devices.
+//! By default, the layout of most ATmega devices is used (EECR at `0x1F`),
+//! such as the ATmega48/88/168/328, the ATmega164/324/644/1284, the
+//! ATmega640/1280/2560, and the ATmega16U4/32U4.
+//! For the classic ATtiny devices (e.g. the ATtiny25/45/85 and the
+//! ATtiny24/44/84) and the older ATmega devices (e.g. the ATmega8/16/32),
+//! which have EECR at `0x1C`, enable the "eeprom-io-0x1c" crate feature.
+//! The devices with memory-mapped flash (i.e. with the "mapped-flash" crate
+//! feature) access their EEPROM via the NVMCTRL peripheral, which is not
+//! supported, thus this module is not available for them.
+//!
+//! Also notice, that the functions of this module are not reentrant, thus
+//! you must not access the EEPROM from interrupt handlers, while the main
+//! program might be accessing it as well.
+//!
+//! [`load`]: EeMem::load
+//! [`store`]: EeMem::store
+//! [`ProgMem`]: crate::wrapper::ProgMem
+
+
+#[cfg(all(target_arch = "avr", not(doc)))]
+use core::arch::asm;
+use core::cell::UnsafeCell;
+#[cfg(target_arch = "avr")]
+use core::mem::size_of;
+#[cfg(target_arch = "avr")]
+use core::mem::MaybeUninit;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::AtomicBool;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::Ordering;
+
+use cfg_if::cfg_if;
+use derivative::Derivative;
+
+#[cfg(doc)]
+use crate::eeprom;
+
+
+
+cfg_if! {
+	if #[cfg(feature = "eeprom-io-0x1c")] {
+		/// The I/O address of the EEPROM control register (EECR).
+		///
+		/// With the "eeprom-io-0x1c" crate feature, the EEPROM registers
+		/// start at `0x1C`, as on the classic ATtiny and the older ATmega
+		/// devices.
+		#[cfg(target_arch = "avr")]
+		pub(crate) const EECR: u8 = 0x1C;
+	} else {
+		/// The I/O address of the EEPROM control register (EECR).
+		///
+		/// By default, the EEPROM registers start at `0x1F`, as on most
+		/// ATmega devices.
+		#[cfg(target_arch = "avr")]
+		pub(crate) const EECR: u8 = 0x1F;
+	}
+}
+/// The I/O address of the EEPROM data register (EEDR).
+#[cfg(target_arch = "avr")]
+const EEDR: u8 = EECR + 1;
+/// The I/O address of the low byte of the EEPROM address register (EEARL).
+#[cfg(target_arch = "avr")]
+const EEARL: u8 = EECR + 2;
+/// The I/O address of the high byte of the EEPROM address register (EEARH).
+#[cfg(target_arch = "avr")]
+const EEARH: u8 = EECR + 3;
+
+/// The spin lock guarding the simulated EEPROM on non-AVR architectures.
+#[cfg(not(target_arch = "avr"))]
+static SIM_LOCK: AtomicBool = AtomicBool::new(false);
+
+/// Execute `f` with exclusive access to the simulated EEPROM.
+///
+/// This is a simple spin lock, thus `f` must be short and must not access
+/// the EEPROM again.
+#[cfg(not(target_arch = "avr"))]
+fn with_sim_lock<R>(f: impl FnOnce() -> R) -> R {
+	while SIM_LOCK
+		.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+		.is_err()
+	{
+		core::hint::spin_loop();
+	}
+
+	let res = f();
+
+	SIM_LOCK.store(false, Ordering::Release);
+
+	res
+}
+
+/// Read a single byte from the EEPROM.
+///
+/// This function waits until any previous write to the EEPROM has finished
+/// and then reads just a single byte from the EEPROM domain.
+///
+/// # Safety
+///
+/// The given pointer must be a valid pointer into the EEPROM domain,
+/// e.g. pointing to a `static` with the `#[link_section = ".eeprom"]`
+/// attribute.
+///
+/// However, the above requirement only applies to the AVR architecture
+/// (`#[cfg(target_arch = "avr")]`), otherwise normal data access primitives
+/// are used. This means that the value must be stored in the regular data
+/// memory domain for ALL OTHER architectures!
+///
+pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported, which
+			// is far more than any AVR chip has EEPROM anyway.
+			let [addr_lo, addr_hi] = (p_addr as u16).to_le_bytes();
+
+			let res: u8;
+
+			// SAFETY: the caller ensures that the address is in the EEPROM.
+			unsafe {
+				asm!(
+					"
+						// Wait for completion of any previous write, i.e.
+						// until EEPE (bit 1 of EECR) is cleared
+						sbic {eecr}, 1
+						rjmp -4
+
+						// Set up the address registers EEARH and EEARL
+						out {eearh}, {addr_hi}
+						out {eearl}, {addr_lo}
+
+						// Start the read by setting EERE (bit 0 of EECR)
+						sbi {eecr}, 0
+
+						// The data is available immediately in EEDR
+						in {res}, {eedr}
+					",
+					addr_hi = in(reg) addr_hi,
+					addr_lo = in(reg) addr_lo,
+					res = out(reg) res,
+					eecr = const EECR,
+					eedr = const EEDR,
+					eearl = const EEARL,
+					eearh = const EEARH,
+					options(nostack),
+				);
+			}
+
+			res
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// This is the non-AVR dummy.
+			// We have to assume that otherwise a normal data segment
+			// would be used, and thus that it is actually save to access it
+			// directly!
+
+			with_sim_lock(|| unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain, because we only support the special
+				// EEPROM domain on AVR. And we hold the lock, thus no other
+				// thread writes it concurrently.
+				core::ptr::read(p_addr)
+			})
+
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Update a single byte in the EEPROM with the byte at `src`.
+///
+/// The EEPROM byte is only written, if it actually differs from the byte
+/// at `src`, because each write wears out the EEPROM a bit.
+///
+/// On AVR, the byte at `src` is only ever read by the assembly, thus it is
+/// fine if it is uninitialized (e.g. padding).
+///
+/// # Safety
+///
+/// `src` must be valid to read a byte from the data domain, and `p_addr`
+/// must be a valid pointer into the EEPROM domain.
+///
+// Inline, so it is only assembled when used, since the AVRtiny cores
+// (which don't have an EEPROM anyway) run out of registers here.
+#[inline]
+unsafe fn update_byte(src: *const u8, p_addr: *mut u8) {
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported, which
+			// is far more than any AVR chip has EEPROM anyway.
+			let [addr_lo, addr_hi] = (p_addr as u16).to_le_bytes();
+
+			// SAFETY: the caller ensures that the addresses are valid.
+			unsafe {
+				asm!(
+					"
+						// Wait for completion of any previous write, i.e.
+						// until EEPE (bit 1 of EECR) is cleared
+						sbic {eecr}, 1
+						rjmp -4
+
+						// Set up the address registers EEARH and EEARL
+						out {eearh}, {addr_hi}
+						out {eearl}, {addr_lo}
+
+						// Read the current value, by setting EERE (bit 0 of EECR)
+						sbi {eecr}, 0
+						in {tmp}, {eedr}
+
+						// Load the new value, and skip the write (i.e. the
+						// following 6 instructions = 12 bytes), if it is equal
+						ld {val}, X
+						cp {tmp}, {val}
+						breq 12
+
+						// Write the new value into EEDR
+						out {eedr}, {val}
+
+						// Disable interrupts, because EEPE must be set within
+						// four clock cycles after EEMPE
+						in {tmp}, 0x3F
+						cli
+
+						// Set EEMPE (bit 2 of EECR) and then EEPE (bit 1 of
+						// EECR) to start the write
+						sbi {eecr}, 2
+						sbi {eecr}, 1
+
+						// Restore the interrupt flag
+						out 0x3F, {tmp}
+					",
+					addr_hi = in(reg) addr_hi,
+					addr_lo = in(reg) addr_lo,
+					tmp = out(reg) _,
+					val = out(reg) _,
+					eecr = const EECR,
+					eedr = const EEDR,
+					eearl = const EEARL,
+					eearh = const EEARH,
+					in("X") src,
+					options(nostack),
+				);
+			}
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// This is the non-AVR dummy, see `read_byte`.
+			with_sim_lock(|| unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain. And we hold the lock, thus no other
+				// thread accesses it concurrently.
+				core::ptr::copy(src, p_addr, 1)
+			})
+
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Write a single byte to the EEPROM.
+///
+/// This function waits until any previous write to the EEPROM has finished
+/// and then starts writing the given byte, without waiting for the write
+/// to complete.
+/// The byte is only written, if it actually differs from the current content,
+/// because each write wears out the EEPROM a bit.
+///
+/// # Safety
+///
+/// The given pointer must be a valid pointer into the EEPROM domain,
+/// e.g. pointing to a `static` with the `#[link_section = ".eeprom"]`
+/// attribute.
+///
+/// However, the above requirement only applies to the AVR architecture
+/// (`#[cfg(target_arch = "avr")]`), otherwise normal data access primitives
+/// are used. This means that the value must be stored in the regular data
+/// memory domain for ALL OTHER architectures!
+///
+#[inline]
+pub unsafe fn write_byte(p_addr: *mut u8, value: u8) {
+	// SAFETY: the caller ensures that `p_addr` is valid, and `value` is
+	// just a local.
+	unsafe { update_byte(&value, p_addr) }
+}
+
+/// Read a single `T` from the EEPROM and return it by value.
+///
+/// On AVR, the value is read byte-wise via [`read_byte`].
+///
+/// # Safety
+///
+/// The given pointer must be a valid pointer into the EEPROM domain
+/// and be valid to read one entire value of type `T`, which must be
+/// initialized.
+///
+/// While the alignment is not strictly required for AVR, the non-AVR fallback
+/// might be actually using `core::ptr::read` and therefore the pointer
+/// must be aligned.
+///
+pub unsafe fn read_value<T>(p_addr: *const T) -> T
+where
+	T: Sized + Copy,
+{
+	cfg_if! {
+		if #[cfg(not(target_arch = "avr"))] {
+			// Read the entire value at once, so that it is not torn by a
+			// concurrent `write_value`
+			with_sim_lock(|| unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain, see `read_byte`.
+				core::ptr::read(p_addr)
+			})
+		} else {
+			let mut buffer = MaybeUninit::<T>::uninit();
+
+			let res: *mut u8 = buffer.as_mut_ptr().cast();
+			let src: *const u8 = p_addr.cast();
+
+			for i in 0..size_of::<T>() {
+				unsafe {
+					// SAFETY: the caller ensures that `p_addr` is valid for an
+					// entire `T`, and `buffer` has the space for it.
+					res.add(i).write(read_byte(src.add(i)))
+				}
+			}
+
+			unsafe {
+				// SAFETY: After the loop, it wrote an entire `T` into `buffer`.
+				buffer.assume_init()
+			}
+		}
+	}
+}
+
+/// Write a single `T` to the EEPROM.
+///
+/// The value is written byte-wise, while only those bytes are actually
+/// written, which differ from the current content, because each write wears
+/// out the EEPROM a bit.
+///
+/// # Safety
+///
+/// The given pointer must be a valid pointer into the EEPROM domain
+/// and be valid to write one entire value of type `T`.
+///
+/// While the alignment is not strictly required for AVR, the non-AVR fallback
+/// might be actually using `core::ptr::write` and therefore the pointer
+/// must be aligned.
+///
+pub unsafe fn write_value<T>(p_addr: *mut T, value: T)
+where
+	T: Sized + Copy,
+{
+	cfg_if! {
+		if #[cfg(not(target_arch = "avr"))] {
+			with_sim_lock(|| unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain, see `read_byte`. And we hold the lock,
+				// thus no other thread accesses it concurrently.
+				core::ptr::write(p_addr, value)
+			})
+		} else {
+			let src: *const u8 = core::ptr::addr_of!(value).cast();
+			let dst: *mut u8 = p_addr.cast();
+
+			for i in 0..size_of::<T>() {
+				unsafe {
+					// SAFETY: the caller ensures that `p_addr` is valid for an
+					// entire `T`, and `value` is just a local `T`.
+					update_byte(src.add(i), dst.add(i))
+				}
+			}
+		}
+	}
+}
+
+
+
+/// Best-effort safe wrapper around a value in the EEPROM.
+///
+/// This type wraps a pointer to a value that is stored in the EEPROM,
+/// and offers safe functions to [`load`](EeMem::load) and
+/// [`store`](EeMem::store) that value.
+///
+/// Analog to the [`ProgMem`](crate::wrapper::ProgMem) wrapper, its
+/// constructor is `unsafe`, and it is recommended to use the [`eeprom!`] macro
+/// instead, which will put the given data into a hidden `static` in the EEPROM
+/// and provide you with an accessible static containing the pointer to it
+/// wrapped in `EeMem`.
+///
+/// Since this is just a fancy pointer type, it can always be copied/cloned
+/// (just copies the address). It also implements `Debug`, which simply prints
+/// the address (into the EEPROM) of the wrapped value.
+///
+/// On AVR, only devices with the EEPROM registers at the I/O addresses
+/// `0x1F`-`0x22` (e.g. the ATmega328 or ATmega2560), or at `0x1C`-`0x1F`
+/// with the "eeprom-io-0x1c" crate feature (e.g. the ATtiny85 or ATmega8),
+/// are supported, see the [module documentation](self).
+///
+///
+/// # Safety
+///
+/// The `target` pointer in this struct must point to a valid object of type
+/// `T` that is stored in the EEPROM domain.
+/// The object must be initialized, readable, and writable.
+/// Also the `target` pointer must be valid for the `'static` lifetime.
+///
+/// However, the requirement about the EEPROM domain only applies
+/// to the AVR architecture (`#[cfg(target_arch = "avr")]`),
+/// otherwise normal data access primitives are used.
+/// This means that the value must be stored in the
+/// regular data memory domain for ALL OTHER architectures!
+///
+//
+//
+// SAFETY: Must not be publicly creatable
+#[non_exhaustive]
+//
+// We use Derivative here to get rid of the constraint on the impls, which
+// a normal derive would add.
+#[derive(Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct EeMem<T: ?Sized> {
+	/// Points to some `T` in the EEPROM.
+	///
+	/// # Safety
+	///
+	/// See the struct doc.
+	target: *mut T,
+}
+
+/// Implement `uDebug` by hand, because the derive variant adds a sized constraint.
+///
+#[cfg(feature = "ufmt")]
+impl<T: ?Sized> ufmt::uDebug for EeMem<T> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("EeMem")?
+			.field("target", &self.target.cast_const().cast::<()>())?
+			.finish()
+	}
+}
+
+unsafe impl<T: ?Sized> Send for EeMem<T> {
+	// SAFETY: pointers per-se are sound to send & share.
+	// On AVR, there is just a single core, and the EEPROM must not be
+	// accessed from interrupt handlers, as documented at the module level.
+	// Otherwise, all accesses to the simulated EEPROM are serialized by the
+	// `SIM_LOCK`, so concurrent loads and stores from multiple threads do not
+	// race.
+}
+
+unsafe impl<T: ?Sized> Sync for EeMem<T> {
+	// SAFETY: pointers per-se are sound to send & share.
+	// On AVR, there is just a single core, and the EEPROM must not be
+	// accessed from interrupt handlers, as documented at the module level.
+	// Otherwise, all accesses to the simulated EEPROM are serialized by the
+	// `SIM_LOCK`, so concurrent loads and stores from multiple threads do not
+	// race.
+}
+
+impl<T: ?Sized> EeMem<T> {
+	/// Return the raw pointer to the inner value.
+	///
+	/// Notice that the returned pointer is indeed a pointer into the EEPROM
+	/// domain! It may **never** be dereferenced via the default Rust operations.
+	///
+	pub fn as_ptr(&self) -> *mut T {
+		self.target
+	}
+}
+
+impl<T> EeMem<T> {
+	/// Construct a new instance of this type.
+	///
+	/// You should not need to call this function directly.
+	/// It is recommended to use the [`eeprom!`] macro instead (which calls
+	/// this constructor for you, while enforcing its contract.
+	///
+	///
+	/// # Safety
+	///
+	/// This function is only sound to call, if the value to which `target`
+	/// points is stored in a `static` that is stored in the EEPROM,
+	/// e.g. by using the attribute `#[link_section = ".eeprom"]`.
+	///
+	/// However, the above requirement about the EEPROM domain only
+	/// applies to the AVR architecture (`#[cfg(target_arch = "avr")]`),
+	/// otherwise normal data access primitives are used,
+	/// and thus the `target` pointer needs to point to writable normal data
+	/// on those architectures.
+	///
+	pub const unsafe fn new(target: *mut T) -> Self {
+		EeMem {
+			target,
+		}
+	}
+}
+
+impl<T: Copy> EeMem<T> {
+	/// Read the inner value from the EEPROM and return a regular value.
+	pub fn load(&self) -> T {
+		// SAFETY: This is safe, because the invariant of this struct
+		// guarantees that this value (i.e. target) is stored in the EEPROM,
+		// which is what `read_value` requires from us.
+		unsafe { read_value(self.target) }
+	}
+
+	/// Write the given value into the EEPROM.
+	///
+	/// Only those bytes, that actually changed, are written, thus storing
+	/// the same value again does not wear out the EEPROM.
+	pub fn store(&self, value: T) {
+		// SAFETY: This is safe, because the invariant of this struct
+		// guarantees that this value (i.e. target) is stored in the EEPROM,
+		// which is what `write_value` requires from us.
+		unsafe { write_value(self.target, value) }
+	}
+}
+
+/// Utilities to work with an array in the EEPROM.
+impl<T, const N: usize> EeMem<[T; N]> {
+	/// Get a reference to an element from the array, without loading it.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the array.
+	pub fn at(&self, idx: usize) -> EeMem<T> {
+		// Just use the slice impl
+		self.as_slice().at(idx)
+	}
+
+	/// Returns the length of the array (i.e. `N`)
+	pub fn len(&self) -> usize {
+		N
+	}
+
+	/// Returns whether the array is empty (i.e. `N == 0`)
+	pub fn is_empty(&self) -> bool {
+		N == 0
+	}
+
+	/// Coerce this array wrapper into a slice wrapper.
+	pub const fn as_slice(&self) -> EeMem<[T]> {
+		EeMem {
+			target: self.target,
+		}
+	}
+}
+
+/// Loading and storing elements of an array in the EEPROM.
+impl<T: Copy, const N: usize> EeMem<[T; N]> {
+	/// Load a single element from the inner array.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the inner type.
+	pub fn load_at(&self, idx: usize) -> T {
+		self.at(idx).load()
+	}
+
+	/// Store a single element into the inner array.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the inner type.
+	pub fn store_at(&self, idx: usize, value: T) {
+		self.at(idx).store(value)
+	}
+
+	/// Lazily iterate over all elements
+	///
+	/// Returns an iterator which lazily loads the elements one at a time
+	/// from the EEPROM.
+	pub fn iter(&self) -> EeIter<T, N> {
+		EeIter::new(self)
+	}
+}
+
+/// Utilities to work with an slice wrapper.
+impl<T> EeMem<[T]> {
+	/// Get a reference to an element from the slice, without loading it.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length of the slice.
+	pub fn at(&self, idx: usize) -> EeMem<T> {
+		// SAFETY: check that `idx` is in bounds
+		assert!(idx < self.target.len(), "Given index is out of bounds");
+
+		let first_element_ptr: *mut T = self.target.cast();
+
+		// Get a point to the selected element
+		let element_ptr = first_element_ptr.wrapping_add(idx);
+
+		// This sound, because `self.target` is in the EEPROM and we checked
+		// above that `idx` is in bound, thus that element pointer is also
+		// valid and pointing into the EEPROM.
+		EeMem {
+			target: element_ptr,
+		}
+	}
+
+	/// Returns the length of the slice
+	pub fn len(&self) -> usize {
+		self.target.len()
+	}
+
+	/// Returns whether the slice is empty
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Loading and storing elements of a slice in the EEPROM.
+impl<T: Copy> EeMem<[T]> {
+	/// Load a single element from the slice.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length of the slice.
+	pub fn load_at(&self, idx: usize) -> T {
+		self.at(idx).load()
+	}
+
+	/// Store a single element into the slice.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length of the slice.
+	pub fn store_at(&self, idx: usize, value: T) {
+		self.at(idx).store(value)
+	}
+}
+
+/// An iterator over an array in the EEPROM.
+///
+/// Can be acquired via [`EeMem::iter`].
+pub struct EeIter<'a, T, const N: usize> {
+	eemem: &'a EeMem<[T; N]>,
+	current_idx: usize,
+}
+
+impl<'a, T, const N: usize> EeIter<'a, T, N> {
+	/// Creates a new iterator over the given EEPROM array.
+	pub const fn new(ee: &'a EeMem<[T; N]>) -> Self {
+		Self {
+			eemem: ee,
+			current_idx: 0,
+		}
+	}
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for EeIter<'a, T, N> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// Check for iterator end
+		if self.current_idx < N {
+			// Load next item from the EEPROM
+			let b = self.eemem.load_at(self.current_idx);
+			self.current_idx += 1;
+
+			Some(b)
+		} else {
+			None
+		}
+	}
+}
+
+/// Same as [`EeMem::iter`]
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a EeMem<[T; N]> {
+	type IntoIter = EeIter<'a, T, N>;
+	type Item = T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+
+/// Only for internal use, the storage of an `eeprom` static.
+///
+/// This is needed, because the value of the static is mutated behind the
+/// back of the compiler.
+#[doc(hidden)]
+#[repr(transparent)]
+pub struct EeCell<T>(UnsafeCell<T>);
+
+unsafe impl<T> Sync for EeCell<T> {
+	// SAFETY: the content is only ever accessed via `EeMem`, see there.
+}
+
+impl<T> EeCell<T> {
+	#[doc(hidden)]
+	pub const fn new(value: T) -> Self {
+		Self(UnsafeCell::new(value))
+	}
+
+	#[doc(hidden)]
+	pub const fn get(&self) -> *mut T {
+		self.0.get()
+	}
+}
+
+
+/// Define a static in the EEPROM.
+///
+/// This is the EEPROM counterpart of the [`progmem`](crate::progmem) macro.
+/// It creates a hidden `static` in the `.eeprom` section with the given
+/// initial value, and a public `static` containing an [`EeMem`] pointing
+/// to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use avr_progmem::eeprom;
+///
+/// eeprom!{
+///     /// A single value
+///     pub static eeprom COUNTER: u16 = 0;
+///
+///     /// An array of values
+///     pub static eeprom SLOTS: [u8; 4] = [1, 2, 3, 4];
+///
+///     /// An "auto-sized" array
+///     pub static eeprom <const NAME_LEN: usize> NAME: [u8; NAME_LEN] = *b"avr";
+/// }
+///
+/// COUNTER.store(COUNTER.load() + 1);
+/// assert_eq!(1, COUNTER.load());
+///
+/// SLOTS.store_at(2, 42);
+/// assert_eq!(42, SLOTS.load_at(2));
+/// assert_eq!([1, 2, 42, 4], SLOTS.load());
+///
+/// // Iterate the array lazily loading one element after another
+/// let mut elem_iter = SLOTS.iter();
+/// assert_eq!(Some(1), elem_iter.next());
+/// assert_eq!(Some(2), elem_iter.next());
+/// assert_eq!(Some(42), elem_iter.next());
+/// assert_eq!(Some(4), elem_iter.next());
+/// assert_eq!(None, elem_iter.next());
+///
+/// assert_eq!(3, NAME_LEN);
+/// assert_eq!(*b"avr", NAME.load());
+/// ```
+///
+#[macro_export]
+macro_rules! eeprom {
+	// Standard rule
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static eeprom $( < const $size_name:ident : usize > )? $name:ident : $ty:ty = $value:expr ;
+
+		$($rest:tt)*
+	) => {
+		// Crate the EEPROM static via internal macro
+		$crate::eeprom_internal!{
+			$(#[$attr])* $vis static eeprom $( < const $size_name : usize > )? $name : $ty = $value;
+		}
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::eeprom!{
+			$($rest)*
+		}
+	};
+
+	// Empty rule
+	() => ()
+}
+
+/// Only for internal use. Use the `eeprom!` macro instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eeprom_internal {
+	// The rule creating an auto-sized EEPROM static via `EeMem`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static eeprom < const $size_name:ident : usize > $name:ident : $ty:ty = $value:expr ;
+	} => {
+		// Create a constant with the size of the value, same as `progmem`.
+		$vis const $size_name : usize = {
+			$value.len()
+		};
+
+		// Just a normal EEPROM static, `$ty` may use the above constant
+		$crate::eeprom_internal!{
+			$( #[ $attr ] )*
+			$vis static eeprom $name : $ty = $value ;
+		}
+	};
+
+	// The normal rule creating an EEPROM static via `EeMem`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static eeprom $name:ident : $ty:ty = $value:expr ;
+	} => {
+		// User attributes
+		$(#[$attr])*
+		// The facade static definition, this only contains a pointer and thus
+		// is NOT in the EEPROM, which in turn makes it safe & sound to access
+		// this facade.
+		$vis static $name: $crate::eeprom::EeMem<$ty> = {
+			// This inner hidden static contains the actual real raw value.
+			//
+			// SAFETY: it must be stored in the EEPROM section!
+			// The `link_section` lets us define that:
+			#[cfg_attr(target_arch = "avr", link_section = ".eeprom")]
+			static VALUE: $crate::eeprom::EeCell<$ty> = $crate::eeprom::EeCell::new($value);
+
+			unsafe {
+				// SAFETY: This call is sound because we ensure with the above
+				// `link_section` attribute on `VALUE` that it is indeed
+				// in the EEPROM section.
+				$crate::eeprom::EeMem::new(
+					VALUE.get()
+				)
+			}
+		};
+	};
+}
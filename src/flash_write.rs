@@ -0,0 +1,711 @@
+//! Self-programming of the program memory.
+//!
+//! AVR chips can write their own program memory via the `spm` instruction,
+//! which is what boot loaders use to update the firmware, but it may also
+//! be used to e.g. store a data log in the flash.
+//!
+//! The program memory is organized in pages (e.g. 128 bytes on the
+//! ATmega328P or 256 bytes on the ATmega2560), which can only be erased and
+//! written as a whole. Writing a page is done in three steps:
+//!
+//! 1. the page is erased via [`erase_page`],
+//! 2. the temporary page buffer is filled word by word via
+//!    [`fill_page_buffer`],
+//! 3. the page buffer is written to the page via [`write_page`].
+//!
+//! Afterwards, [`enable_rww`] must be called, before the application section
+//! (the so-called Read-While-Write section) can be read again.
+//!
+//! As convenience, the [`ProgMemPage`] wrapper does all this for you,
+//! and the `page` rule of the [`progmem!`] macro defines a page-aligned
+//! `static` in progmem, which may be written at runtime:
+//!
+//! ```rust
+//! use avr_progmem::progmem;
+//!
+//! progmem!{
+//!     /// A page in progmem, which may be rewritten at runtime
+//!     static progmem page LOG: [u8; 128] = [0xFF; 128];
+//! }
+//!
+//! let mut data = [0; 128];
+//! data[..5].copy_from_slice(b"Hello");
+//!
+//! // SAFETY: the page is not accessed otherwise while being written
+//! unsafe { LOG.write(&data) };
+//! assert_eq!(data, LOG.load());
+//! ```
+//!
+//! Notice, that on most AVR chips, the `spm` instruction only has an effect,
+//! if it is executed from the boot loader section of the program memory.
+//! Therefore, all functions of this module are `#[inline(always)]`, so that
+//! the `spm` instruction ends up in the code of the caller, which must be
+//! placed in the boot loader section (e.g. via the `link_section` attribute
+//! and a respective linker script).
+//! Also notice, that pages beyond the 64 KiB limit are not supported.
+//!
+//! On AVR, only the devices with the SPMCSR register at the I/O address
+//! `0x37` are supported, which are most devices with self-programming (e.g.
+//! the ATmega328, the ATmega2560, or the ATtiny85), but not e.g. the ATmega64
+//! and ATmega128. Since any EEPROM write must be completed before, the EEPROM
+//! control register is used as well, see the [`eeprom`](mod@crate::eeprom) module
+//! for its supported layouts.
+//! The newer AVR cores with memory-mapped flash (see the "mapped-flash" and
+//! "avrtiny" crate features), which are programmed via their NVM controller
+//! instead, and the cores without the `spm` instruction (see the "lpm-r0"
+//! crate feature) are not supported, thus this module is not available for
+//! them.
+//!
+//! On all other architectures than AVR, the program memory is simulated by
+//! the normal data memory of the `static`, which allows to test the page logic
+//! on your host.
+//! The simulated page buffer is guarded by a global lock, and remembers the
+//! address of each filled word, so that writing a page panics if the buffer
+//! was filled for another page.
+//! The [`ProgMemPage`] methods hold another global lock of the simulation
+//! while erasing or writing their page, so that distinct pages may be written
+//! from multiple threads, e.g. by concurrent tests.
+//!
+//! [`progmem!`]: crate::progmem
+
+
+#[cfg(all(target_arch = "avr", not(doc)))]
+use core::arch::asm;
+#[cfg(not(target_arch = "avr"))]
+use core::cell::UnsafeCell;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::AtomicBool;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::Ordering;
+
+use cfg_if::cfg_if;
+use derivative::Derivative;
+
+#[cfg(all(target_arch = "avr", not(doc)))]
+use crate::eeprom::EECR;
+use crate::wrapper::ProgMem;
+
+
+
+/// The I/O address of the store program memory control and status register
+/// (SPMCSR).
+#[cfg(all(target_arch = "avr", not(doc)))]
+const SPMCSR: u8 = 0x37;
+/// The `SPMCSR` value to fill the temporary page buffer (`SPMEN`).
+#[cfg(all(target_arch = "avr", not(doc)))]
+const SPM_FILL: u8 = 0x01;
+/// The `SPMCSR` value to erase a page (`PGERS | SPMEN`).
+#[cfg(all(target_arch = "avr", not(doc)))]
+const SPM_ERASE: u8 = 0x03;
+/// The `SPMCSR` value to write a page (`PGWRT | SPMEN`).
+#[cfg(all(target_arch = "avr", not(doc)))]
+const SPM_WRITE: u8 = 0x05;
+/// The `SPMCSR` value to re-enable the RWW section (`RWWSRE | SPMEN`).
+#[cfg(all(target_arch = "avr", not(doc)))]
+const SPM_RWW_ENABLE: u8 = 0x11;
+
+/// The size in words of the simulated page buffer, which is the maximum
+/// page size of all AVR chips (i.e. 256 bytes).
+#[cfg(not(target_arch = "avr"))]
+const SIM_BUFFER_WORDS: usize = 128;
+
+/// The simulated temporary page buffer.
+///
+/// Each filled word is stored along with its address, unfilled words are
+/// `None` (i.e. all ones). Like on real hardware, it is reset after writing
+/// a page.
+#[cfg(not(target_arch = "avr"))]
+type SimPageBuffer = [Option<(usize, u16)>; SIM_BUFFER_WORDS];
+
+/// The storage of the simulated page buffer, guarded by the `SIM_LOCK`.
+#[cfg(not(target_arch = "avr"))]
+struct SimCell(UnsafeCell<SimPageBuffer>);
+
+#[cfg(not(target_arch = "avr"))]
+unsafe impl Sync for SimCell {
+	// SAFETY: the content is only accessed while holding the `SIM_LOCK`.
+}
+
+#[cfg(not(target_arch = "avr"))]
+static SIM_PAGE_BUFFER: SimCell = SimCell(UnsafeCell::new([None; SIM_BUFFER_WORDS]));
+
+/// The spin lock guarding the simulated page buffer on non-AVR
+/// architectures.
+#[cfg(not(target_arch = "avr"))]
+static SIM_LOCK: AtomicBool = AtomicBool::new(false);
+
+/// Execute `f` with exclusive access to the simulated page buffer.
+///
+/// This is a simple spin lock, thus `f` must not panic and must not use the
+/// page buffer again (i.e. it must use the `sim_*` functions directly).
+#[cfg(not(target_arch = "avr"))]
+fn with_sim_buffer<R>(f: impl FnOnce(&mut SimPageBuffer) -> R) -> R {
+	while SIM_LOCK
+		.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+		.is_err()
+	{
+		core::hint::spin_loop();
+	}
+
+	// SAFETY: we hold the lock, thus we have exclusive access.
+	let res = f(unsafe { &mut *SIM_PAGE_BUFFER.0.get() });
+
+	SIM_LOCK.store(false, Ordering::Release);
+
+	res
+}
+
+/// The spin lock serializing the erasing and writing of entire pages on
+/// non-AVR architectures, see [`SimSession`].
+#[cfg(not(target_arch = "avr"))]
+static SIM_SESSION_LOCK: AtomicBool = AtomicBool::new(false);
+
+/// Exclusive use of the simulated page programming for an entire sequence of
+/// page operations, as acquired by the [`ProgMemPage`] methods.
+///
+/// The lock is released when this guard is dropped, even on panic.
+#[cfg(not(target_arch = "avr"))]
+struct SimSession;
+
+#[cfg(not(target_arch = "avr"))]
+impl SimSession {
+	/// Wait until no other page is being erased or written.
+	fn acquire() -> Self {
+		while SIM_SESSION_LOCK
+			.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+			.is_err()
+		{
+			core::hint::spin_loop();
+		}
+
+		SimSession
+	}
+}
+
+#[cfg(not(target_arch = "avr"))]
+impl Drop for SimSession {
+	fn drop(&mut self) {
+		SIM_SESSION_LOCK.store(false, Ordering::Release);
+	}
+}
+
+/// Simulates filling the word at `addr` into the page buffer.
+#[cfg(not(target_arch = "avr"))]
+fn sim_fill(buffer: &mut SimPageBuffer, addr: usize, word: u16) {
+	buffer[(addr / 2) % SIM_BUFFER_WORDS] = Some((addr, word));
+}
+
+/// Simulates erasing the page at `page` of `size` bytes.
+///
+/// # Safety
+///
+/// `page` must point to writable memory of `size` bytes.
+#[cfg(not(target_arch = "avr"))]
+unsafe fn sim_erase(page: *mut u8, size: usize) {
+	unsafe {
+		// SAFETY: the caller ensures that `page` points to writable memory.
+		core::ptr::write_bytes(page, 0xFF, size)
+	}
+}
+
+/// Simulates writing the page buffer into the page at `page` of `size` bytes
+/// and resets the buffer.
+///
+/// Returns `false`, if the buffer contained words filled for another page,
+/// which are discarded.
+///
+/// # Safety
+///
+/// `page` must point to writable memory of `size` bytes.
+#[cfg(not(target_arch = "avr"))]
+unsafe fn sim_write(buffer: &mut SimPageBuffer, page: *mut u8, size: usize) -> bool {
+	let mut only_this_page = true;
+
+	for slot in buffer.iter_mut() {
+		if let Some((addr, word)) = slot.take() {
+			let offset = addr.wrapping_sub(page as usize);
+			if offset >= size {
+				only_this_page = false;
+				continue;
+			}
+
+			for (j, b) in word.to_le_bytes().iter().enumerate() {
+				unsafe {
+					// SAFETY: the caller ensures that `page` points to
+					// writable memory, and `offset` is a word-aligned offset
+					// within that page.
+					// Like real flash, writing can only clear bits.
+					*page.add(offset + j) &= *b;
+				}
+			}
+		}
+	}
+
+	only_this_page
+}
+
+
+/// Executes a single `spm` command.
+///
+/// This waits for the completion of any previous `spm` command and EEPROM
+/// write, and then executes the given `spm` command with interrupts disabled,
+/// since `spm` must be executed within four clock cycles after writing
+/// `SPMCSR`.
+///
+/// # Safety
+///
+/// `cmd` must be a valid `SPMCSR` command and `z` a valid address for that
+/// command.
+#[cfg(all(target_arch = "avr", not(doc)))]
+#[inline(always)]
+unsafe fn spm(cmd: u8, z: *const u8) {
+	unsafe {
+		asm!(
+			"
+				// Wait for completion of a previous `spm`, i.e. until
+				// SPMEN (bit 0 of SPMCSR) is cleared
+				in {tmp}, {spmcsr}
+				sbrc {tmp}, 0
+				rjmp -6
+
+				// Wait for completion of a previous EEPROM write, i.e. until
+				// EEPE (bit 1 of EECR) is cleared
+				sbic {eecr}, 1
+				rjmp -4
+
+				// Disable interrupts, because `spm` must follow within four
+				// clock cycles after writing SPMCSR
+				in {tmp}, 0x3F
+				cli
+
+				// Execute the command
+				out {spmcsr}, {cmd}
+				spm
+
+				// Restore the interrupt flag
+				out 0x3F, {tmp}
+			",
+			tmp = out(reg) _,
+			cmd = in(reg) cmd,
+			spmcsr = const SPMCSR,
+			eecr = const EECR,
+			in("Z") z,
+			options(nostack),
+		);
+	}
+}
+
+
+/// Writes a single word into the temporary page buffer.
+///
+/// The position of the word within the page buffer is given by the lower
+/// bits of `addr`, i.e. `addr` is the address of the word within the page,
+/// which is going to be written via [`write_page`].
+///
+/// # Safety
+///
+/// `addr` must be a word-aligned (i.e. even) address in the program memory.
+///
+/// On AVR, this function must be executed from the boot loader section.
+///
+/// # Panics
+///
+/// This function panics, if `addr` is not word-aligned.
+///
+#[inline(always)]
+pub unsafe fn fill_page_buffer(addr: *const u8, word: u16) {
+	assert!(addr as usize % 2 == 0, "The address must be word-aligned");
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			unsafe {
+				// SAFETY: the caller ensures the validity of `addr`
+				spm_fill(word, addr)
+			}
+		} else if #[cfg(not(target_arch = "avr"))] {
+			with_sim_buffer(|buffer| sim_fill(buffer, addr as usize, word));
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Executes the page buffer fill command with `word` in `r1:r0`.
+///
+/// # Safety
+///
+/// See [`fill_page_buffer`].
+#[cfg(all(target_arch = "avr", not(doc)))]
+#[inline(always)]
+unsafe fn spm_fill(word: u16, z: *const u8) {
+	unsafe {
+		asm!(
+			"
+				// Wait for completion of a previous `spm`, i.e. until
+				// SPMEN (bit 0 of SPMCSR) is cleared
+				in {tmp}, {spmcsr}
+				sbrc {tmp}, 0
+				rjmp -6
+
+				// Wait for completion of a previous EEPROM write, i.e. until
+				// EEPE (bit 1 of EECR) is cleared
+				sbic {eecr}, 1
+				rjmp -4
+
+				// The word to fill is passed in r1:r0, save r0, since we
+				// must restore it
+				mov {save}, r0
+				movw r0, {word}
+
+				// Disable interrupts, because `spm` must follow within four
+				// clock cycles after writing SPMCSR
+				in {tmp}, 0x3F
+				cli
+
+				// Execute the command
+				out {spmcsr}, {cmd}
+				spm
+
+				// Restore the interrupt flag
+				out 0x3F, {tmp}
+
+				// Restore r0 and the zero register
+				mov r0, {save}
+				clr r1
+			",
+			tmp = out(reg) _,
+			save = out(reg) _,
+			word = in(reg_pair) word,
+			cmd = in(reg) SPM_FILL,
+			spmcsr = const SPMCSR,
+			eecr = const EECR,
+			in("Z") z,
+			options(nostack),
+		);
+	}
+}
+
+/// Erases an entire page of the program memory.
+///
+/// Afterwards, all bytes of the page read as `0xFF`.
+///
+/// # Safety
+///
+/// `page` must be the address of a page of size `PAGE_SIZE` in the program
+/// memory, which is not used for code.
+/// `PAGE_SIZE` must be the page size of the AVR chip.
+///
+/// On AVR, this function must be executed from the boot loader section.
+/// On all other architectures, `page` must point to writable memory of
+/// `PAGE_SIZE` bytes.
+///
+/// # Panics
+///
+/// This function panics, if `page` is not aligned to `PAGE_SIZE`.
+///
+#[inline(always)]
+pub unsafe fn erase_page<const PAGE_SIZE: usize>(page: *const u8) {
+	assert!(
+		page as usize % PAGE_SIZE == 0,
+		"The address must be page-aligned"
+	);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			unsafe {
+				// SAFETY: the caller ensures the validity of `page`
+				spm(SPM_ERASE, page)
+			}
+		} else if #[cfg(not(target_arch = "avr"))] {
+			with_sim_buffer(|_| unsafe {
+				// SAFETY: we are not on AVR, and the caller ensures that
+				// `page` points to writable memory.
+				sim_erase(page as *mut u8, PAGE_SIZE)
+			});
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Writes the temporary page buffer into a page of the program memory.
+///
+/// Notice, that writing can only clear bits, thus the page must be erased
+/// via [`erase_page`] before.
+/// Afterwards, the temporary page buffer is reset.
+///
+/// # Safety
+///
+/// `page` must be the address of a page of size `PAGE_SIZE` in the program
+/// memory, which is not used for code.
+/// `PAGE_SIZE` must be the page size of the AVR chip.
+///
+/// On AVR, this function must be executed from the boot loader section.
+/// On all other architectures, `page` must point to writable memory of
+/// `PAGE_SIZE` bytes.
+///
+/// # Panics
+///
+/// This function panics, if `page` is not aligned to `PAGE_SIZE`.
+/// On all other architectures than AVR, it also panics, if the page buffer
+/// was filled for another page.
+///
+#[inline(always)]
+pub unsafe fn write_page<const PAGE_SIZE: usize>(page: *const u8) {
+	assert!(
+		page as usize % PAGE_SIZE == 0,
+		"The address must be page-aligned"
+	);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			unsafe {
+				// SAFETY: the caller ensures the validity of `page`
+				spm(SPM_WRITE, page)
+			}
+		} else if #[cfg(not(target_arch = "avr"))] {
+			let only_this_page = with_sim_buffer(|buffer| unsafe {
+				// SAFETY: we are not on AVR, and the caller ensures that
+				// `page` points to writable memory.
+				sim_write(buffer, page as *mut u8, PAGE_SIZE)
+			});
+			assert!(only_this_page, "The page buffer was filled for another page");
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Re-enables the Read-While-Write section.
+///
+/// While a page is erased or written, the RWW section (i.e. the application
+/// section) can not be read. Therefore, this function must be called after
+/// [`erase_page`] or [`write_page`], before the program memory can be read
+/// again, it waits for any running operation to complete.
+///
+/// # Safety
+///
+/// On AVR, this function must be executed from the boot loader section.
+///
+#[inline(always)]
+pub unsafe fn enable_rww() {
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			unsafe {
+				// SAFETY: this command does not use the Z register
+				spm(SPM_RWW_ENABLE, core::ptr::null())
+			}
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// Nothing to do, the simulated flash is always readable
+		} else {
+			// Special case, this neither possibly documentation on AVR, any
+			// it case is problematic, so if we reach this, we just abort via
+			// panic.
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+
+
+/// A page in the program memory, which may be written at runtime.
+///
+/// This type wraps a pointer to a page-aligned array of `SIZE` bytes in
+/// the program memory, which may be erased and written via [`erase`] and
+/// [`write`], and read via [`load`] or [`as_progmem`].
+/// `SIZE` must be the page size of the AVR chip.
+///
+/// Use the `page` rule of the [`progmem!`] macro to define such a page.
+///
+/// Since this is just a fancy pointer type, it can always be
+/// copied/cloned (just copies the address).
+///
+///
+/// # Safety
+///
+/// The `target` pointer in this struct must point to a valid, page-aligned
+/// array of `SIZE` bytes that is stored in the program memory domain,
+/// and which is not used for anything else.
+/// Also the `target` pointer must be valid for the `'static` lifetime.
+///
+/// However, the requirement about the program memory domain only applies
+/// to the AVR architecture (`#[cfg(target_arch = "avr")]`),
+/// otherwise normal data access primitives are used, and the array must be
+/// writable.
+///
+/// [`erase`]: ProgMemPage::erase
+/// [`write`]: ProgMemPage::write
+/// [`load`]: ProgMemPage::load
+/// [`as_progmem`]: ProgMemPage::as_progmem
+/// [`progmem!`]: crate::progmem
+///
+//
+//
+// SAFETY: Must not be publicly creatable
+#[non_exhaustive]
+//
+// We use Derivative here for consistency with `ProgMem`.
+#[derive(Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct ProgMemPage<const SIZE: usize> {
+	/// Points to the page in progmem.
+	///
+	/// # Safety
+	///
+	/// See the struct doc.
+	target: *mut [u8; SIZE],
+}
+
+#[cfg(feature = "ufmt")]
+impl<const SIZE: usize> ufmt::uDebug for ProgMemPage<SIZE> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("ProgMemPage")?
+			.field("target", &self.target.cast_const().cast::<()>())?
+			.finish()
+	}
+}
+
+unsafe impl<const SIZE: usize> Send for ProgMemPage<SIZE> {
+	// SAFETY: pointers per-se are sound to send & share.
+	// Erasing and writing the page is `unsafe`, and its callers ensure that
+	// the page is not accessed otherwise in the meantime. Reading the page is
+	// done via `lpm` (or the simulation), thus the compiler never observes a
+	// mutation.
+}
+
+unsafe impl<const SIZE: usize> Sync for ProgMemPage<SIZE> {
+	// SAFETY: pointers per-se are sound to send & share.
+	// Erasing and writing the page is `unsafe`, and its callers ensure that
+	// the page is not accessed otherwise in the meantime. Reading the page is
+	// done via `lpm` (or the simulation), thus the compiler never observes a
+	// mutation.
+}
+
+impl<const SIZE: usize> ProgMemPage<SIZE> {
+	/// Construct a new instance of this type.
+	///
+	/// You should not need to call this function directly.
+	/// It is recommended to use the [`progmem!`](crate::progmem) macro
+	/// instead.
+	///
+	/// # Safety
+	///
+	/// See the struct doc.
+	///
+	/// # Panics
+	///
+	/// This function panics, if `SIZE` is not a power of two of at least two
+	/// bytes (i.e. a single word).
+	pub const unsafe fn new(target: *mut [u8; SIZE]) -> Self {
+		assert!(
+			SIZE.is_power_of_two() && SIZE >= 2,
+			"The page size must be a power of two of at least one word"
+		);
+
+		ProgMemPage {
+			target,
+		}
+	}
+
+	/// Return the raw pointer to the page.
+	///
+	/// Notice that the returned pointer is indeed a pointer into the progmem
+	/// domain! It may **never** be dereferenced via the default Rust operations.
+	pub fn as_ptr(&self) -> *const [u8; SIZE] {
+		self.target
+	}
+
+	/// Get a wrapper to read the page.
+	pub fn as_progmem(&self) -> ProgMem<[u8; SIZE]> {
+		// SAFETY: the invariant of this struct guarantees that the page is
+		// in progmem.
+		unsafe { ProgMem::new(self.target) }
+	}
+
+	/// Read the page from progmem.
+	pub fn load(&self) -> [u8; SIZE] {
+		self.as_progmem().load()
+	}
+
+	/// Erase the page, i.e. set all its bytes to `0xFF`.
+	///
+	/// On AVR, this method must be executed from the boot loader section.
+	///
+	/// # Safety
+	///
+	/// See [`write`](Self::write).
+	///
+	/// # Panics
+	///
+	/// This method panics, if the page is not aligned to `SIZE`.
+	#[inline(always)]
+	pub unsafe fn erase(&self) {
+		#[cfg(not(target_arch = "avr"))]
+		let _session = SimSession::acquire();
+
+		// SAFETY: the invariant of this struct guarantees that the page is
+		// in progmem and not used otherwise.
+		unsafe {
+			erase_page::<SIZE>(self.target.cast());
+			enable_rww();
+		}
+	}
+
+	/// Write the given data into the page.
+	///
+	/// This erases the page, fills the page buffer with the given `data`,
+	/// writes it into the page, and finally re-enables the RWW section, so the
+	/// page can be read again.
+	///
+	/// On AVR, this method must be executed from the boot loader section.
+	///
+	/// # Safety
+	///
+	/// The page must not be accessed otherwise while it is written, i.e.:
+	///
+	/// * it must not be read at the same time (e.g. from an interrupt
+	///   handler or another thread),
+	/// * with the "mapped-flash" crate feature, no references to the page
+	///   (see `ProgMem::as_ref`) may be alive,
+	/// * and no other page may be erased or written at the same time, because
+	///   the page buffer is shared by all pages.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the page is not aligned to `SIZE`.
+	#[inline(always)]
+	pub unsafe fn write(&self, data: &[u8; SIZE]) {
+		let page: *const u8 = self.target.cast();
+
+		// Keep other pages from using the simulated page buffer meanwhile,
+		// so that writing distinct pages concurrently is fine
+		#[cfg(not(target_arch = "avr"))]
+		let _session = SimSession::acquire();
+
+		// SAFETY: the invariant of this struct guarantees that the page is
+		// in progmem, aligned, and not used otherwise.
+		unsafe {
+			erase_page::<SIZE>(page);
+
+			for i in (0..SIZE).step_by(2) {
+				let word = u16::from_le_bytes([data[i], data[i + 1]]);
+				fill_page_buffer(page.wrapping_add(i), word);
+			}
+
+			write_page::<SIZE>(page);
+			enable_rww();
+		}
+	}
+}
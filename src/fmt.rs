@@ -0,0 +1,948 @@
+//! Formatting with format strings in progmem
+//!
+//! A formatting macro such as `ufmt::uwrite!(w, "Temp: {} C", t)` stores the
+//! literal pieces of its format string, i.e. `"Temp: "` and `" C"`, as `&str`
+//! in RAM.
+//! The [`pm_uwrite`](crate::pm_uwrite) and [`pm_write`](crate::pm_write)
+//! macros of this module are drop-in replacements, which split the format
+//! string at compile-time and put its literal pieces into progmem instead
+//! (packed into a single [`PmStringTable`](crate::string::PmStringTable)).
+//! At runtime, these pieces are streamed one `char` at a time to the writer,
+//! interleaved with the arguments.
+//!
+//! * [`pm_uwrite`](crate::pm_uwrite) writes to any `ufmt::uWrite` or
+//!   `ufmt::Formatter` (requires the `ufmt` crate feature), and
+//! * [`pm_write`](crate::pm_write) writes to any `core::fmt::Write` or
+//!   `core::fmt::Formatter`.
+//!
+//! Additionally, the [`pm_format_args`](crate::pm_format_args) macro wraps
+//! formatted data with the format string in progmem as some `impl Display`,
+//! analog to `core::format_args!`.
+//!
+//! The format strings of [`pm_write`](crate::pm_write) and
+//! [`pm_format_args`](crate::pm_format_args) support the same format options
+//! as `core::fmt`, such as `{:>8}`, `{:#06x}`, or `{:.2}`, except that width
+//! and precision must be given literally.
+//! Notice, padding with a fill char other than space (e.g. `{:*^8}`) is
+//! applied by hand to the entire output of the argument, even for types that
+//! otherwise ignore the width (such as the `Debug` impl of `str`).
+//! The format strings of [`pm_uwrite`](crate::pm_uwrite) may only contain
+//! `{}` (for `uDisplay`) and `{:?}` (for `uDebug`) placeholders, just like
+//! `ufmt::uwrite!`.
+//!
+//! In both cases, each placeholder takes the next argument, i.e. neither
+//! positional nor named arguments are supported, and the `{{` and `}}`
+//! escapes may be used.
+//! Any other format string is rejected at compile-time.
+//!
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(feature = "ufmt")] // requires the `ufmt` crate feature
+//! # {
+//! #
+//! use avr_progmem::pm_uwrite;
+//! use avr_progmem::pm_write;
+//!
+//! fn report<W: ufmt::uWrite>(writer: &mut W, temp: i16) -> Result<(), W::Error> {
+//!     // Only the argument is in RAM, "Temp: " and " C" are in progmem
+//!     pm_uwrite!(writer, "Temp: {} C", temp)
+//! }
+//! #
+//! # struct MyWriter(String);
+//! # impl ufmt::uWrite for MyWriter {
+//! #     type Error = ();
+//! #     fn write_str(&mut self, s: &str) -> Result<(),()> {
+//! #         self.0.push_str(s);
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let mut writer = MyWriter(String::new());
+//! # report(&mut writer, -5).unwrap();
+//! # assert_eq!("Temp: -5 C", writer.0);
+//!
+//! // Same with `core::fmt`
+//! let mut text = String::new();
+//! pm_write!(&mut text, "{} is {:?}", "大賢者", 'x').unwrap();
+//! assert_eq!("大賢者 is 'x'", text);
+//! # }
+//! ```
+
+
+use core::fmt;
+
+/// Re-export for the `pm_uwrite` macro.
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+pub use ufmt;
+
+use crate::string::PmStr;
+
+
+
+/// The kind of a `{}` placeholder
+#[doc(hidden)]
+pub const DISPLAY: u8 = 0;
+/// The kind of a `{:?}` placeholder
+#[doc(hidden)]
+pub const DEBUG: u8 = 1;
+/// The kind of a `{:x}` placeholder
+#[doc(hidden)]
+pub const LOWER_HEX: u8 = 2;
+/// The kind of a `{:X}` placeholder
+#[doc(hidden)]
+pub const UPPER_HEX: u8 = 3;
+/// The kind of a `{:o}` placeholder
+#[doc(hidden)]
+pub const OCTAL: u8 = 4;
+/// The kind of a `{:b}` placeholder
+#[doc(hidden)]
+pub const BINARY: u8 = 5;
+/// The kind of a `{:e}` placeholder
+#[doc(hidden)]
+pub const LOWER_EXP: u8 = 6;
+/// The kind of a `{:E}` placeholder
+#[doc(hidden)]
+pub const UPPER_EXP: u8 = 7;
+
+/// The alignment of a placeholder
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Align {
+	/// No alignment given, i.e. the default of the argument type
+	Unknown,
+	Left,
+	Center,
+	Right,
+}
+
+/// The options of a placeholder, i.e. everything but its kind
+///
+/// This is the equivalent of the format spec of `core::fmt` (e.g. the
+/// `+>8.2` of `{:+>8.2}`).
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Spec {
+	fill: char,
+	align: Align,
+	plus: bool,
+	alternate: bool,
+	zero: bool,
+	width: Option<usize>,
+	precision: Option<usize>,
+}
+
+impl Spec {
+	/// The options of a plain `{}` placeholder
+	const DEFAULT: Spec = Spec {
+		fill: ' ',
+		align: Align::Unknown,
+		plus: false,
+		alternate: false,
+		zero: false,
+		width: None,
+		precision: None,
+	};
+
+	/// Returns whether any option is given.
+	const fn is_default(&self) -> bool {
+		self.fill == ' '
+			&& matches!(self.align, Align::Unknown)
+			&& !self.plus
+			&& !self.alternate
+			&& !self.zero
+			&& self.width.is_none()
+			&& self.precision.is_none()
+	}
+}
+
+/// A token of a format string
+enum Token {
+	/// A single byte of a literal piece
+	Byte(u8),
+	/// A placeholder of the given kind
+	Arg(u8, Spec),
+	/// The end of the format string
+	End,
+}
+
+/// Parses the token at `idx` of the format string, returning it and the index
+/// of the next token.
+///
+/// Panics on invalid or unsupported format strings, which is a compile-time
+/// error, since this is only ever evaluated in a `const` context.
+const fn next_token(fmt: &[u8], idx: usize) -> (Token, usize) {
+	if idx >= fmt.len() {
+		return (Token::End, idx);
+	}
+
+	match fmt[idx] {
+		b'{' if idx + 1 < fmt.len() && fmt[idx + 1] == b'{' => (Token::Byte(b'{'), idx + 2),
+		b'}' if idx + 1 < fmt.len() && fmt[idx + 1] == b'}' => (Token::Byte(b'}'), idx + 2),
+		b'{' => {
+			let mut end = idx + 1;
+			while end < fmt.len() && fmt[end] != b'}' {
+				end += 1;
+			}
+			if end >= fmt.len() {
+				panic!("Invalid format string: unmatched opening brace, escape it by doubling it");
+			}
+
+			let (kind, spec) = parse_placeholder(fmt, idx + 1, end);
+
+			(Token::Arg(kind, spec), end + 1)
+		},
+		b'}' => panic!("Invalid format string: unmatched closing brace, escape it by doubling it"),
+		b => (Token::Byte(b), idx + 1),
+	}
+}
+
+/// Parses the placeholder between the braces at `start..end`, returning its
+/// kind and its options.
+const fn parse_placeholder(fmt: &[u8], start: usize, end: usize) -> (u8, Spec) {
+	let mut spec = Spec::DEFAULT;
+
+	if start == end {
+		return (DISPLAY, spec);
+	}
+	if fmt[start] != b':' {
+		panic!("Unsupported format string: positional and named arguments are not supported");
+	}
+	let mut idx = start + 1;
+
+	// The fill char may be any char, thus it has to be decoded
+	let (fill, fill_len) = decode_char(fmt, idx, end);
+	if idx + fill_len < end && parse_align(fmt[idx + fill_len]).is_some() {
+		spec.fill = fill;
+		idx += fill_len;
+	}
+	if idx < end {
+		if let Some(align) = parse_align(fmt[idx]) {
+			spec.align = align;
+			idx += 1;
+		}
+	}
+
+	if idx < end && fmt[idx] == b'+' {
+		spec.plus = true;
+		idx += 1;
+	} else if idx < end && fmt[idx] == b'-' {
+		// Accepted, but unused, just like in `core::fmt`
+		idx += 1;
+	}
+	if idx < end && fmt[idx] == b'#' {
+		spec.alternate = true;
+		idx += 1;
+	}
+	if idx < end && fmt[idx] == b'0' {
+		spec.zero = true;
+		idx += 1;
+	}
+
+	let (width, next) = parse_count(fmt, idx, end);
+	spec.width = width;
+	idx = next;
+
+	if idx < end && fmt[idx] == b'.' {
+		let (precision, next) = parse_count(fmt, idx + 1, end);
+		if precision.is_none() {
+			panic!("Invalid format string: missing precision after the dot");
+		}
+		spec.precision = precision;
+		idx = next;
+	}
+
+	let kind = match end - idx {
+		0 => DISPLAY,
+		1 => {
+			match fmt[idx] {
+				b'?' => DEBUG,
+				b'x' => LOWER_HEX,
+				b'X' => UPPER_HEX,
+				b'o' => OCTAL,
+				b'b' => BINARY,
+				b'e' => LOWER_EXP,
+				b'E' => UPPER_EXP,
+				_ => panic!("Unsupported format string: unknown format trait"),
+			}
+		},
+		_ => panic!("Unsupported format string: unknown format options"),
+	};
+
+	(kind, spec)
+}
+
+/// Parses the alignment char of a format spec.
+const fn parse_align(b: u8) -> Option<Align> {
+	match b {
+		b'<' => Some(Align::Left),
+		b'^' => Some(Align::Center),
+		b'>' => Some(Align::Right),
+		_ => None,
+	}
+}
+
+/// Parses an optional decimal integer, as used for width and precision.
+const fn parse_count(fmt: &[u8], mut idx: usize, end: usize) -> (Option<usize>, usize) {
+	let mut count = None;
+	while idx < end && fmt[idx].is_ascii_digit() {
+		let digit = (fmt[idx] - b'0') as usize;
+		count = match count {
+			Some(c) => Some(c * 10 + digit),
+			None => Some(digit),
+		};
+		idx += 1;
+	}
+	if idx < end && (fmt[idx] == b'$' || fmt[idx] == b'*') {
+		panic!("Unsupported format string: width and precision must be given literally");
+	}
+	(count, idx)
+}
+
+/// Decodes the UTF-8 char at `idx` (if any), returning it and its length.
+const fn decode_char(fmt: &[u8], idx: usize, end: usize) -> (char, usize) {
+	if idx >= end {
+		return (' ', 0);
+	}
+
+	// The format string is a `str`, thus the sequence is valid
+	let first = fmt[idx] as u32;
+	let (len, init) = match first {
+		0x00..=0x7F => (1, first),
+		0xC0..=0xDF => (2, first & 0x1F),
+		0xE0..=0xEF => (3, first & 0x0F),
+		_ => (4, first & 0x07),
+	};
+	let mut code = init;
+	let mut i = 1;
+	while i < len {
+		code = (code << 6) | (fmt[idx + i] as u32 & 0x3F);
+		i += 1;
+	}
+
+	match char::from_u32(code) {
+		Some(c) => (c, len),
+		None => panic!("Invalid format string: invalid UTF-8"),
+	}
+}
+
+/// Returns the total length of the literal pieces of the format string.
+#[doc(hidden)]
+pub const fn literals_len(fmt: &str) -> usize {
+	let fmt = fmt.as_bytes();
+	let mut len = 0;
+	let mut idx = 0;
+	loop {
+		let (token, next) = next_token(fmt, idx);
+		match token {
+			Token::Byte(_) => len += 1,
+			Token::Arg(_, _) => {},
+			Token::End => return len,
+		}
+		idx = next;
+	}
+}
+
+/// Returns the literal pieces of the format string concatenated.
+///
+/// `N` must be the `literals_len` of the format string.
+#[doc(hidden)]
+pub const fn literals_from_fmt<const N: usize>(fmt: &str) -> [u8; N] {
+	let fmt = fmt.as_bytes();
+	let mut array = [0; N];
+	let mut len = 0;
+	let mut idx = 0;
+	loop {
+		let (token, next) = next_token(fmt, idx);
+		match token {
+			Token::Byte(b) => {
+				array[len] = b;
+				len += 1;
+			},
+			Token::Arg(_, _) => {},
+			Token::End => break,
+		}
+		idx = next;
+	}
+	array
+}
+
+/// Returns the start offsets of the literal pieces of the format string in
+/// the `literals_from_fmt` array, followed by its total length.
+///
+/// `N` must be the number of arguments plus two, otherwise this function
+/// panics, i.e. if the number of arguments does not match the placeholders.
+#[doc(hidden)]
+pub const fn offsets_from_fmt<const N: usize>(fmt: &str) -> [u16; N] {
+	let fmt = fmt.as_bytes();
+	let mut offsets = [0; N];
+	let mut len = 0;
+	let mut piece = 1;
+	let mut idx = 0;
+	loop {
+		let (token, next) = next_token(fmt, idx);
+		match token {
+			Token::Byte(_) => len += 1,
+			Token::Arg(_, _) => {
+				if piece + 1 >= N {
+					panic!("The format string has more placeholders than arguments");
+				}
+				offsets[piece] = len as u16;
+				piece += 1;
+			},
+			Token::End => break,
+		}
+		idx = next;
+	}
+	if piece + 1 != N {
+		panic!("The format string has less placeholders than arguments");
+	}
+	if len > u16::MAX as usize {
+		panic!("The format string is too long");
+	}
+	offsets[piece] = len as u16;
+	offsets
+}
+
+/// Returns the kind and the options of the placeholder of the argument at
+/// `arg_idx`.
+const fn placeholder(fmt: &str, arg_idx: usize) -> (u8, Spec) {
+	let fmt = fmt.as_bytes();
+	let mut arg = 0;
+	let mut idx = 0;
+	loop {
+		let (token, next) = next_token(fmt, idx);
+		match token {
+			Token::Byte(_) => {},
+			Token::Arg(kind, spec) => {
+				if arg == arg_idx {
+					return (kind, spec);
+				}
+				arg += 1;
+			},
+			// Can not happen, `offsets_from_fmt` checks the argument count
+			Token::End => return (DISPLAY, Spec::DEFAULT),
+		}
+		idx = next;
+	}
+}
+
+/// Returns the kind of the placeholder of the argument at `arg_idx`.
+#[doc(hidden)]
+pub const fn arg_kind(fmt: &str, arg_idx: usize) -> u8 {
+	placeholder(fmt, arg_idx).0
+}
+
+/// Returns the options of the placeholder of the argument at `arg_idx`.
+#[doc(hidden)]
+pub const fn arg_spec(fmt: &str, arg_idx: usize) -> Spec {
+	placeholder(fmt, arg_idx).1
+}
+
+/// Asserts that the format string only uses placeholders supported by
+/// `ufmt`, i.e. `{}` and `{:?}` without any options.
+#[doc(hidden)]
+pub const fn assert_ufmt_compatible(fmt: &str) {
+	let fmt = fmt.as_bytes();
+	let mut idx = 0;
+	loop {
+		let (token, next) = next_token(fmt, idx);
+		match token {
+			Token::Byte(_) => {},
+			Token::Arg(kind, spec) => {
+				if !(kind == DISPLAY || kind == DEBUG) || !spec.is_default() {
+					panic!(
+						"Unsupported format string: `ufmt` only supports Display and Debug \
+						 placeholders without any options"
+					);
+				}
+			},
+			Token::End => return,
+		}
+		idx = next;
+	}
+}
+
+
+
+/// An argument of the formatting macros
+///
+/// This wrapper implements `Display` and `uDisplay` via the trait
+/// corresponding to the `KIND` of its placeholder.
+#[doc(hidden)]
+pub struct Arg<'a, T: ?Sized, const KIND: u8>(pub &'a T);
+
+macro_rules! impl_arg_display {
+	($( $kind:ident => $tr:ident ),*) => {
+		$(
+			impl<T: fmt::$tr + ?Sized> fmt::Display for Arg<'_, T, $kind> {
+				fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+					fmt::$tr::fmt(self.0, fmt)
+				}
+			}
+		)*
+	};
+}
+impl_arg_display!(
+	DISPLAY => Display,
+	DEBUG => Debug,
+	LOWER_HEX => LowerHex,
+	UPPER_HEX => UpperHex,
+	OCTAL => Octal,
+	BINARY => Binary,
+	LOWER_EXP => LowerExp,
+	UPPER_EXP => UpperExp
+);
+
+#[cfg(feature = "ufmt")]
+impl<T: ufmt::uDisplay + ?Sized> ufmt::uDisplay for Arg<'_, T, DISPLAY> {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		ufmt::uDisplay::fmt(self.0, fmt)
+	}
+}
+
+#[cfg(feature = "ufmt")]
+impl<T: ufmt::uDebug + ?Sized> ufmt::uDisplay for Arg<'_, T, DEBUG> {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		ufmt::uDebug::fmt(self.0, fmt)
+	}
+}
+
+/// Writes the argument with the given options
+///
+/// This is always inlined, so that the `spec`, which is a constant, selects
+/// just one of the branches at compile-time.
+#[doc(hidden)]
+#[inline(always)]
+pub fn write_arg<W: fmt::Write + ?Sized>(
+	writer: &mut W,
+	arg: &impl fmt::Display,
+	spec: Spec,
+) -> fmt::Result {
+	match spec.width {
+		// A fill char can not be passed dynamically to `core::fmt`, thus pad
+		// it by hand.
+		// Notice, the zero flag overrides the fill and alignment.
+		Some(width) if spec.fill != ' ' && !spec.zero => {
+			let mut counter = CharCounter(0);
+			write_flagged(&mut counter, arg, spec, None)?;
+
+			let padding = width.saturating_sub(counter.0);
+			let (pre, post) = match spec.align {
+				Align::Left => (0, padding),
+				Align::Center => (padding / 2, padding - padding / 2),
+				// A fill char always comes with an alignment
+				_ => (padding, 0),
+			};
+
+			write_fill(writer, spec.fill, pre)?;
+			write_flagged(writer, arg, spec, None)?;
+			write_fill(writer, spec.fill, post)
+		},
+		width => write_flagged(writer, arg, spec, width),
+	}
+}
+
+/// Writes the argument with the flags, alignment, and precision of the given
+/// options and with the given width, if any, but ignoring the fill char.
+#[inline(always)]
+fn write_flagged<W: fmt::Write + ?Sized>(
+	w: &mut W,
+	arg: &impl fmt::Display,
+	spec: Spec,
+	width: Option<usize>,
+) -> fmt::Result {
+	// Expands to a match over all combinations of the flags and precision
+	macro_rules! write_aligned {
+		($align:literal, $width:literal $(, $width_arg:expr)?) => {
+			match (spec.plus, spec.alternate, spec.zero, spec.precision) {
+				(false, false, false, None) => {
+					write!(w, concat!("{:", $align, "", $width, "}"), arg $(, $width_arg)?)
+				},
+				(false, false, true, None) => {
+					write!(w, concat!("{:", $align, "0", $width, "}"), arg $(, $width_arg)?)
+				},
+				(false, true, false, None) => {
+					write!(w, concat!("{:", $align, "#", $width, "}"), arg $(, $width_arg)?)
+				},
+				(false, true, true, None) => {
+					write!(w, concat!("{:", $align, "#0", $width, "}"), arg $(, $width_arg)?)
+				},
+				(true, false, false, None) => {
+					write!(w, concat!("{:", $align, "+", $width, "}"), arg $(, $width_arg)?)
+				},
+				(true, false, true, None) => {
+					write!(w, concat!("{:", $align, "+0", $width, "}"), arg $(, $width_arg)?)
+				},
+				(true, true, false, None) => {
+					write!(w, concat!("{:", $align, "+#", $width, "}"), arg $(, $width_arg)?)
+				},
+				(true, true, true, None) => {
+					write!(w, concat!("{:", $align, "+#0", $width, "}"), arg $(, $width_arg)?)
+				},
+				(false, false, false, Some(p)) => {
+					write!(w, concat!("{:", $align, "", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(false, false, true, Some(p)) => {
+					write!(w, concat!("{:", $align, "0", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(false, true, false, Some(p)) => {
+					write!(w, concat!("{:", $align, "#", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(false, true, true, Some(p)) => {
+					write!(w, concat!("{:", $align, "#0", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(true, false, false, Some(p)) => {
+					write!(w, concat!("{:", $align, "+", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(true, false, true, Some(p)) => {
+					write!(w, concat!("{:", $align, "+0", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(true, true, false, Some(p)) => {
+					write!(w, concat!("{:", $align, "+#", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+				(true, true, true, Some(p)) => {
+					write!(w, concat!("{:", $align, "+#0", $width, ".prec$}"), arg $(, $width_arg)?, prec = p)
+				},
+			}
+		};
+	}
+
+	// Without a width, the argument must see no width at all (instead of
+	// a zero width), thus it must not even appear in the format string.
+	match (spec.align, width) {
+		(Align::Unknown, None) => write_aligned!("", ""),
+		(Align::Unknown, Some(width)) => write_aligned!("", "1$", width),
+		(Align::Left, None) => write_aligned!("<", ""),
+		(Align::Left, Some(width)) => write_aligned!("<", "1$", width),
+		(Align::Center, None) => write_aligned!("^", ""),
+		(Align::Center, Some(width)) => write_aligned!("^", "1$", width),
+		(Align::Right, None) => write_aligned!(">", ""),
+		(Align::Right, Some(width)) => write_aligned!(">", "1$", width),
+	}
+}
+
+/// Writes the fill char `count` times.
+fn write_fill<W: fmt::Write + ?Sized>(w: &mut W, fill: char, count: usize) -> fmt::Result {
+	for _ in 0..count {
+		w.write_char(fill)?;
+	}
+	Ok(())
+}
+
+/// A writer just counting the `char`s written, for padding by hand.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.0 += s.chars().count();
+		Ok(())
+	}
+}
+
+/// Writes the next literal piece, if any
+#[doc(hidden)]
+pub fn write_piece<W: fmt::Write + ?Sized>(
+	writer: &mut W,
+	pieces: &mut impl Iterator<Item = PmStr>,
+) -> fmt::Result {
+	match pieces.next() {
+		Some(piece) => piece.chars().try_for_each(|c| writer.write_char(c)),
+		None => Ok(()),
+	}
+}
+
+/// Writes the next literal piece, if any
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+pub fn uwrite_piece<W: ufmt::uWrite + ?Sized>(
+	fmt: &mut ufmt::Formatter<W>,
+	pieces: &mut impl Iterator<Item = PmStr>,
+) -> Result<(), W::Error> {
+	match pieces.next() {
+		Some(piece) => ufmt::uDisplay::fmt(&piece, fmt),
+		None => Ok(()),
+	}
+}
+
+/// Calls `f` with the given writer, analog to `ufmt`'s `do_as_formatter`.
+///
+/// This fixes the error type of the closure, and reborrows the writer.
+#[doc(hidden)]
+pub fn do_with_writer<W: fmt::Write + ?Sized>(
+	writer: &mut W,
+	f: impl FnOnce(&mut W) -> fmt::Result,
+) -> fmt::Result {
+	f(writer)
+}
+
+/// Formatted data with the format string in progmem
+///
+/// This type is returned by the [`pm_format_args`](crate::pm_format_args)
+/// macro, it writes the formatted data via its `Display` impl.
+pub struct PmArguments<F> {
+	/// Writes the formatted data
+	write: F,
+}
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> PmArguments<F> {
+	/// Only for internal use. Use the `pm_format_args!` macro instead.
+	#[doc(hidden)]
+	pub fn new(write: F) -> Self {
+		Self {
+			write,
+		}
+	}
+}
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for PmArguments<F> {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		(self.write)(fmt)
+	}
+}
+
+
+
+/// Only for internal use. Use the `pm_uwrite!` or `pm_write!` macros instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! pm_fmt_internal {
+	// Creates the table of the literal pieces of the format string, the
+	// format string must be available as `FMT`
+	(@pieces $( $arg:expr ),* ) => {{
+		// This bit runs at compile-time
+		const ARGS: usize = <[&str]>::len(&[ $( ::core::stringify!($arg) ),* ]);
+
+		// This inner hidden static contains all the literal pieces
+		// concatenated.
+		//
+		// SAFETY: it must be stored in the progmem or text section!
+		// The `link_section` lets us define that:
+		#[cfg_attr(target_arch = "avr", link_section = ".progmem.data")]
+		static BLOB: [u8; $crate::fmt::literals_len(FMT)] =
+			$crate::fmt::literals_from_fmt(FMT);
+
+		// This inner hidden static contains the start offsets of the pieces
+		// in `BLOB`, followed by the total length.
+		//
+		// SAFETY: it must be stored in the progmem or text section!
+		#[cfg_attr(target_arch = "avr", link_section = ".progmem.data")]
+		static OFFSETS: [u16; ARGS + 2] = $crate::fmt::offsets_from_fmt(FMT);
+
+		let (blob, offsets) = unsafe {
+			// SAFETY: This call is sound because we ensure with the above
+			// `link_section` attributes that both are indeed in the
+			// progmem section.
+			(
+				$crate::progmem_wrap!(BLOB),
+				$crate::progmem_wrap!(OFFSETS),
+			)
+		};
+
+		unsafe {
+			// SAFETY: This call is sound, because we started out with a
+			// `&str` and only split it at ASCII chars, thus each piece must
+			// be valid UTF-8
+			$crate::string::PmStringTable::new(blob.as_slice(), offsets.as_slice())
+		}
+	}};
+
+	// Writes the arguments each followed by the next piece via `ufmt`
+	(@uargs $f:ident, $pieces:ident, [ $( $idx:tt )* ] ) => {};
+	(@uargs $f:ident, $pieces:ident, [ $( $idx:tt )* ] $arg:expr $( , $rest:expr )* ) => {
+		$crate::fmt::ufmt::uDisplay::fmt(
+			&$crate::fmt::Arg::<_, { $crate::fmt::arg_kind(FMT, 0 $( $idx )*) }>(&$arg),
+			$f,
+		)?;
+		$crate::fmt::uwrite_piece($f, &mut $pieces)?;
+		$crate::pm_fmt_internal!(@uargs $f, $pieces, [ $( $idx )* + 1 ] $( $rest ),* );
+	};
+
+	// Writes the arguments each followed by the next piece via `core::fmt`
+	(@args $w:ident, $pieces:ident, [ $( $idx:tt )* ] ) => {};
+	(@args $w:ident, $pieces:ident, [ $( $idx:tt )* ] $arg:expr $( , $rest:expr )* ) => {
+		$crate::fmt::write_arg(
+			$w,
+			&$crate::fmt::Arg::<_, { $crate::fmt::arg_kind(FMT, 0 $( $idx )*) }>(&$arg),
+			{
+				// This bit runs at compile-time
+				const SPEC: $crate::fmt::Spec = $crate::fmt::arg_spec(FMT, 0 $( $idx )*);
+				SPEC
+			},
+		)?;
+		$crate::fmt::write_piece($w, &mut $pieces)?;
+		$crate::pm_fmt_internal!(@args $w, $pieces, [ $( $idx )* + 1 ] $( $rest ),* );
+	};
+}
+
+/// Write formatted data into a `ufmt::uWrite` with the format string in
+/// progmem
+///
+/// This macro is a drop-in replacement for `ufmt::uwrite!`, except that the
+/// literal pieces of the format string are put into progmem, and only
+/// `{}` and `{:?}` placeholders are supported.
+/// Like `uwrite!` it accepts either a `&mut impl uWrite` or a
+/// `&mut ufmt::Formatter` and returns a `Result<(), W::Error>`.
+///
+/// See the [module doc](crate::fmt) for details.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::pm_uwrite;
+///
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// impl ufmt::uDisplay for Point {
+///     fn fmt<W: ufmt::uWrite + ?Sized>(
+///         &self,
+///         f: &mut ufmt::Formatter<W>,
+///     ) -> Result<(), W::Error> {
+///         // Also works on a `Formatter`
+///         pm_uwrite!(f, "({}, {})", self.x, self.y)
+///     }
+/// }
+///
+/// # struct MyWriter(String);
+/// # impl ufmt::uWrite for MyWriter {
+/// #     type Error = ();
+/// #     fn write_str(&mut self, s: &str) -> Result<(),()> {
+/// #         self.0.push_str(s);
+/// #         Ok(())
+/// #     }
+/// # }
+/// let mut writer = // impl uWrite
+/// #    MyWriter(String::new());
+///     /* SNIP */;
+///
+/// pm_uwrite!(&mut writer, "{{p}} = {}", Point { x: 1, y: -2 }).unwrap();
+/// # assert_eq!("{p} = (1, -2)", writer.0);
+/// ```
+///
+#[cfg(feature = "ufmt")]
+#[macro_export]
+macro_rules! pm_uwrite {
+	($writer:expr, $fmt:literal $( , $arg:expr )* $(,)? ) => {
+		$crate::fmt::ufmt::UnstableDoAsFormatter::do_as_formatter($writer, |f| {
+			const FMT: &str = $fmt;
+			const _: () = $crate::fmt::assert_ufmt_compatible(FMT);
+			let mut pieces = $crate::pm_fmt_internal!(@pieces $( $arg ),* ).iter();
+
+			$crate::fmt::uwrite_piece(f, &mut pieces)?;
+			$crate::pm_fmt_internal!(@uargs f, pieces, [] $( $arg ),* );
+
+			Ok(())
+		})
+	};
+}
+
+/// Write formatted data into a `core::fmt::Write` with the format string in
+/// progmem
+///
+/// This macro is a drop-in replacement for `core::write!`, except that the
+/// literal pieces of the format string are put into progmem, and the
+/// arguments can not be referred to by position or name.
+/// Like `write!` it accepts either a `&mut impl Write` or a
+/// `&mut core::fmt::Formatter` and returns a `core::fmt::Result`.
+///
+/// See the [module doc](crate::fmt) for details.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::pm_write;
+/// use core::fmt;
+///
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// impl fmt::Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         // Also works on a `Formatter`
+///         pm_write!(f, "({}, {})", self.x, self.y)
+///     }
+/// }
+///
+/// let mut text = String::new();
+/// pm_write!(&mut text, "{{p}} = {}", Point { x: 1, y: -2 }).unwrap();
+/// assert_eq!("{p} = (1, -2)", text);
+///
+/// // The format options of `core::fmt` are supported, too
+/// let mut text = String::new();
+/// pm_write!(&mut text, "[{:>8}] [{:*^7.1}] {:#06x}", "right", 2.75, 42).unwrap();
+/// assert_eq!("[   right] [**2.8**] 0x002a", text);
+///
+/// // Just like with `write!`, the argument only sees a width if given
+/// struct Width;
+///
+/// impl fmt::Display for Width {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{:?}", f.width())
+///     }
+/// }
+///
+/// let mut text = String::new();
+/// pm_write!(&mut text, "{} {:3}", Width, Width).unwrap();
+/// assert_eq!("None Some(3)", text);
+/// ```
+///
+#[macro_export]
+macro_rules! pm_write {
+	($writer:expr, $fmt:literal $( , $arg:expr )* $(,)? ) => {
+		$crate::fmt::do_with_writer($writer, |w| {
+			const FMT: &str = $fmt;
+			let mut pieces = $crate::pm_fmt_internal!(@pieces $( $arg ),* ).iter();
+
+			$crate::fmt::write_piece(w, &mut pieces)?;
+			$crate::pm_fmt_internal!(@args w, pieces, [] $( $arg ),* );
+
+			Ok(())
+		})
+	};
+}
+
+/// Creates a `Display` value from a format string in progmem
+///
+/// This macro is the equivalent of `core::format_args!` for the
+/// [`pm_write`](crate::pm_write) macro, i.e. it accepts the same format
+/// strings, but returns some `impl Display`, which writes the formatted data
+/// when it is displayed.
+/// This allows to pass formatted data to anything that accepts a `Display`,
+/// e.g. as argument to another formatting macro.
+///
+/// Notice, unlike `format_args!`, the arguments are evaluated each time the
+/// returned value is displayed.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::pm_format_args;
+///
+/// let (x, y) = (3, -4);
+/// let point = pm_format_args!("({:+}, {:+})", x, y);
+///
+/// assert_eq!("Point: (+3, -4)", format!("Point: {}", point));
+/// ```
+///
+#[macro_export]
+macro_rules! pm_format_args {
+	($fmt:literal $( , $arg:expr )* $(,)? ) => {
+		$crate::fmt::PmArguments::new(|f: &mut ::core::fmt::Formatter| {
+			$crate::pm_write!(f, $fmt $( , $arg )* )
+		})
+	};
+}
@@ -8,6 +8,10 @@
 // We to access the length of a slice pointer (for unsized `ProgMem`s)
 #![feature(slice_ptr_len)]
 //
+// We pass the EEPROM and SPM I/O register addresses as constants to the
+// inline assembly
+#![cfg_attr(target_arch = "avr", feature(asm_const))]
+//
 // Allow to implement `CoerceUnsized` on `ProgMem`
 #![cfg_attr(feature = "unsize", feature(coerce_unsized))]
 //
@@ -30,6 +34,12 @@
 //! to simplify working with it,
 //! as well as a [`PmString`](crate::string::PmString) wrapper for string
 //! handling.
+//! Similarly, the [`EeMem`](crate::eeprom::EeMem) wrapper offers access to data
+//! stored in the EEPROM of an AVR micro-controller.
+//! And the [`flash_write`](crate::flash_write) module allows to rewrite pages of
+//! the program memory at runtime.
+//! Finally, the [`PmLut`](crate::lut::PmLut) wrapper interpolates curves
+//! stored as lookup tables in the program memory.
 //!
 //! This crate is implemented only in Rust and some short assembly, it does NOT
 //! depend on the [`avr-libc`] or any other C-library. However, due to the use
@@ -276,6 +286,45 @@
 //! # }
 //! ```
 //!
+//! To keep the format strings of your `uwrite!`s and `write!`s out of the RAM
+//! as well, you can use the [`pm_uwrite`] respectively [`pm_write`] macro
+//! (see the [`fmt`](crate::fmt) module), which put the literal pieces of the
+//! format string into progmem.
+//!
+//!
+//! # Memory-mapped Flash
+//!
+//! Newer AVR cores, such as the ATtiny 0/1/2-series or the ATmega 0-series
+//! (e.g. the ATmega4809), map their entire program memory into the data address
+//! space, so it can be read with ordinary load instructions.
+//! On those chips, you can enable the "mapped-flash" crate feature
+//! (or "mapped-flash-0x4000" for the ATmega 0-series), and all the data loading
+//! functions of this crate will read through the mapped window instead of using
+//! the `lpm` instruction (see [`MAPPED_FLASH_OFFSET`](crate::raw::MAPPED_FLASH_OFFSET)).
+//! Additionally, this enables [`ProgMem::as_ref`](crate::wrapper::ProgMem::as_ref),
+//! which gives you a plain reference to the data, without copying it into RAM.
+//!
+//! The same holds for the reduced-core AVRtiny devices (ATtiny4/5/9/10/20/40),
+//! which don't even support the `lpm` instruction in the form used by this
+//! crate, but map their flash at `0x4000` as well.
+//! For those, enable the "avrtiny" crate feature.
+//!
+//! On the other end, the classic AVR1/AVR2 cores (e.g. the ATtiny26) only
+//! support the implicit form of `lpm`, which always loads into `r0` and does
+//! not post-increment the address.
+//! For those, enable the "lpm-r0" crate feature.
+//!
+//! Notice, that the AVR family can not be detected automatically, because Rust
+//! has no `cfg` for the target CPU, hence the crate features.
+//!
+//!
+//! # Instrumentation
+//!
+//! To find out how often your code actually hits the flash, you can enable
+//! the "instrument" crate feature, which counts all progmem reads (and the
+//! bytes read), in total and per watched static, see the
+//! [`instrument`](crate::raw::instrument) module.
+//!
 //!
 //! # Other Architectures
 //!
@@ -293,33 +342,49 @@
 //! broken. However, it is an important point to know when for instance writing
 //! a library that is not limited to AVR.
 //!
+//! The downside of this fallback is that accidentally dereferencing a progmem
+//! pointer directly (e.g. via `ProgMem::as_ptr`), which is undefined behavior
+//! on AVR, goes unnoticed in such tests.
+//! To catch these bugs, you can enable the "strict-host" crate feature (e.g.
+//! only for your tests), then all progmem pointers are tagged on 64-bit
+//! non-AVR targets, so that such direct accesses crash, while the accessors
+//! of this crate keep working.
+//!
 //!
 //! # Implementation Limitations
 //!
 //! Aside from what has been already been covered, the current implementation
 //! has two further limitations.
 //!
-//! First, since this crate uses an inline assembly loop on a 8-bit
-//! architecture, the loop counter only allows values up to 255. This means
-//! that not more that 255 bytes can be loaded at once with any of the methods
-//! of this crate. However, this only applies to a single continuous load
-//! operation, so for instance `ProgMem<[u8;1024]>::load()` will panic, but
-//! accessing such a big type in smaller chunks e.g.
-//! `ProgMem<[u8;1024]>::load_sub_array::<[u8;128]>(512)` is perfectly fine
-//! because the to be loaded type `[u8;128]` is only 128 bytes in size.
-//! Notice that the same limitation holds for `PmString<N>::load()`
-//! (i.e. you can only use it if `N <= 255` holds.
-//! On the other hand, there is no such limitation on `PmString<N>::chars()`
-//! and `PmString`'s `Display`/`uDisplay` implementation,
-//! because those, just load each `char` individually
+//! First, the inline assembly loop uses a 16-bit counter, thus not more than
+//! 64 kiB can be loaded at once with any of the methods of this crate.
+//! In practice, the stack is the far tighter limit: a single continuous load
+//! operation, such as `ProgMem<[u8;1024]>::load()`, needs the entire value on
+//! the stack, which e.g. an Arduino Uno with its 2 kiB of RAM can hardly afford.
+//! Accessing such a big type in smaller chunks e.g.
+//! `ProgMem<[u8;1024]>::load_sub_array::<[u8;128]>(512)` only needs 128 bytes.
+//! The same holds for `PmString<N>::load()`, while `PmString<N>::chars()`
+//! and `PmString`'s `Display`/`uDisplay` implementation
+//! just load each `char` individually
 //! (i.e. no more that 4 bytes at a time).
 //!
-//! Second, since this crate only uses the `lpm` instruction, which is limited
-//! by a 16-bit pointer, this crate may only be used with data stored in the
-//! lower 64 kiB of program memory. Since this property has not be tested it is
-//! unclear whether it will cause a panic or right-up undefined behavior, so be
-//! very wary when working with AVR chips that have more then 64 kiB of program
-//! memory.
+//! Second, the `lpm` instruction, which is used by [`ProgMem`](crate::wrapper::ProgMem)
+//! and friends, is limited by a 16-bit pointer, thus by default those may only
+//! be used with data stored in the lower 64 kiB of program memory.
+//! On AVR chips that have more then 64 kiB of program memory, you can use the
+//! `far` rule of the [`progmem!`] macro instead, which yields a
+//! [`ProgMemFar`](crate::wrapper::ProgMemFar) that uses the `elpm`
+//! instruction and can access data anywhere in the program memory.
+//! Alternatively, you can enable the "elpm" crate feature, then all the
+//! statics of the [`progmem!`] macro (except for the writable pages) are
+//! loaded via `elpm` from their full address, regardless where they ended up.
+//! This requires `#![feature(asm_experimental_arch)]` in your crate, and must
+//! not be used on AVR chips without `elpm`.
+//!
+//! To find out where your progmem statics actually ended up, you can use the
+//! `avr-progmem-size` tool from the `tools` directory of this crate's
+//! repository, which lists all progmem statics of a firmware ELF and flags
+//! the ones above 64 kiB.
 //!
 //! [`progmem!`]: https://docs.rs/avr-progmem/latest/avr_progmem/macro.progmem.html
 //! [`avr-libc`]: https://crates.io/crates/avr-libc
@@ -328,6 +393,16 @@
 
 
 
+// The EEPROM of the cores with memory-mapped flash is accessed via the
+// NVMCTRL peripheral, which is not supported (yet)
+#[cfg(not(all(target_arch = "avr", feature = "mapped-flash")))]
+pub mod eeprom;
+// The cores with memory-mapped flash are programmed via the NVMCTRL
+// peripheral, and the cores of the "lpm-r0" feature lack the `spm` instruction
+#[cfg(not(all(target_arch = "avr", any(feature = "mapped-flash", feature = "lpm-r0"))))]
+pub mod flash_write;
+pub mod fmt;
+pub mod lut;
 pub mod raw;
 pub mod string;
 pub mod wrapper;
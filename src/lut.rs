@@ -0,0 +1,265 @@
+//! Interpolating lookup tables in progmem.
+//!
+//! A typical use of the program memory are curves that are too expensive to
+//! compute at runtime, such as thermistor curves, gamma tables, or waveforms.
+//! This module offers the [`PmLut`] struct, which wraps a progmem array of
+//! breakpoints, i.e. `(x, y)` pairs sorted by `x`, and looks up the `y` for
+//! any given `x` by interpolating between the neighbouring breakpoints.
+//!
+//! All the math is done with integers (see the [`Lerp`] trait), thus no
+//! floating point support is needed, which is rather expensive on AVR.
+//!
+//! ```rust
+//! use avr_progmem::lut::PmLut;
+//! use avr_progmem::progmem;
+//!
+//! progmem!{
+//!     /// ADC reading to temperature in 0.1 °C, sorted by the ADC reading
+//!     static progmem THERMISTOR: [(u16, i16); 4] = [
+//!         (100, 1200),
+//!         (300, 600),
+//!         (600, 250),
+//!         (900, -100),
+//!     ];
+//! }
+//!
+//! let curve = PmLut::new(THERMISTOR);
+//!
+//! // Exact breakpoints
+//! assert_eq!(600, curve.linear(300));
+//! // Interpolated between (300, 600) and (600, 250)
+//! assert_eq!(425, curve.linear(450));
+//! // Clamped to the first and last breakpoint
+//! assert_eq!(1200, curve.linear(0));
+//! assert_eq!(-100, curve.linear(1023));
+//! // Or just take the nearest breakpoint
+//! assert_eq!(250, curve.nearest(500));
+//! ```
+//!
+//! Notice, that the breakpoints must be sorted by `x` in ascending order,
+//! otherwise the results are meaningless.
+
+
+use core::cmp::Ordering;
+use core::ptr::addr_of;
+
+use derivative::Derivative;
+
+use crate::wrapper::ProgMem;
+
+
+
+/// Integer types that can be interpolated linearly.
+///
+/// This trait is implemented for all primitive integer types up to 32 bits,
+/// and may be implemented for custom (e.g. fixed-point) types to use them
+/// with [`PmLut`].
+/// The `x` of a [`PmLut`] additionally needs to be `Ord`, while the `y` only
+/// needs to implement this trait for [`linear`](PmLut::linear) lookups.
+pub trait Lerp: Copy {
+	/// Returns the distance from `self` to `to`, where `to >= self`.
+	fn distance(self, to: Self) -> u32;
+
+	/// Interpolates linearly from `self` towards `to` by the fraction
+	/// `num / den`, where `num <= den` and `den > 0`.
+	///
+	/// The result is rounded towards `self`.
+	fn lerp(self, to: Self, num: u16, den: u16) -> Self;
+}
+
+macro_rules! impl_lerp {
+	($($t:ty => $wide:ty),*) => {
+		$(
+			impl Lerp for $t {
+				fn distance(self, to: Self) -> u32 {
+					(to as $wide - self as $wide) as u32
+				}
+
+				fn lerp(self, to: Self, num: u16, den: u16) -> Self {
+					let delta = to as $wide - self as $wide;
+
+					// The result lies between `self` and `to`, so it fits
+					(self as $wide + delta * num as $wide / den as $wide) as Self
+				}
+			}
+		)*
+	};
+}
+// The wide types are chosen, such that `delta * num` can not overflow
+impl_lerp!(u8 => i32, i8 => i32, u16 => i64, i16 => i64, u32 => i64, i32 => i64);
+
+
+/// An interpolating lookup table in progmem.
+///
+/// This type wraps a progmem array of `N` breakpoints, i.e. `(x, y)` pairs
+/// sorted by `x` in ascending order, and maps any `x` to a `y` via
+/// [`linear`](Self::linear) interpolation or by taking the
+/// [`nearest`](Self::nearest) breakpoint.
+/// Values of `x` beyond the first or last breakpoint are clamped to that
+/// breakpoint.
+///
+/// Each lookup binary searches the breakpoints by loading only the `x` of
+/// each probed breakpoint, and then loads only the `y` of the one or two
+/// breakpoints around the given `x` (their `x` was already probed), thus it
+/// is well suited for large tables.
+///
+/// See the [module doc](self) for an example.
+#[derive(Derivative)]
+// This is just a pointer type/wrapper thus it is safe & sound to just copy it.
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct PmLut<X, Y, const N: usize> {
+	/// The breakpoints sorted by `x`
+	table: ProgMem<[(X, Y); N]>,
+}
+
+/// Implement `uDebug` by hand, analog to `ProgMem`.
+#[cfg(feature = "ufmt")]
+impl<X, Y, const N: usize> ufmt::uDebug for PmLut<X, Y, N> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("PmLut")?
+			.field("table", &self.table)?
+			.finish()
+	}
+}
+
+impl<X, Y, const N: usize> PmLut<X, Y, N> {
+	/// Creates a new lookup table over the given breakpoints.
+	///
+	/// The breakpoints must be sorted by `x` in ascending order, otherwise
+	/// the lookups return meaningless results.
+	pub const fn new(table: ProgMem<[(X, Y); N]>) -> Self {
+		Self {
+			table,
+		}
+	}
+
+	/// Returns the underlying progmem array of breakpoints.
+	pub fn as_progmem(&self) -> &ProgMem<[(X, Y); N]> {
+		&self.table
+	}
+}
+
+impl<X: Lerp + Ord, Y: Lerp, const N: usize> PmLut<X, Y, N> {
+	/// Looks up `x` interpolating linearly between the neighbouring
+	/// breakpoints.
+	///
+	/// If `x` lies beyond the first or last breakpoint, the `y` of that
+	/// breakpoint is returned.
+	///
+	/// The fraction of the interpolation has a precision of 16 bits, i.e.
+	/// if neighbouring breakpoints are more than `u16::MAX` apart, the
+	/// fraction is rounded down.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the table is empty.
+	///
+	pub fn linear(&self, x: X) -> Y {
+		match self.neighbours(x) {
+			Neighbours::Hit(_, y) => y,
+			Neighbours::Between((x0, y0), (x1, y1)) => {
+				let mut num = x0.distance(x);
+				let mut den = x0.distance(x1);
+
+				// Reduce the fraction to 16 bits
+				while den > u16::MAX as u32 {
+					num >>= 1;
+					den >>= 1;
+				}
+
+				y0.lerp(y1, num as u16, den as u16)
+			},
+		}
+	}
+}
+
+impl<X: Lerp + Ord, Y: Copy, const N: usize> PmLut<X, Y, N> {
+	/// Looks up the `y` of the breakpoint nearest to `x`.
+	///
+	/// If `x` lies exactly in the middle of two breakpoints, the lower one
+	/// is taken.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the table is empty.
+	///
+	pub fn nearest(&self, x: X) -> Y {
+		match self.neighbours(x) {
+			Neighbours::Hit(_, y) => y,
+			Neighbours::Between((x0, y0), (x1, y1)) => {
+				if x0.distance(x) <= x.distance(x1) {
+					y0
+				} else {
+					y1
+				}
+			},
+		}
+	}
+
+	/// Searches the breakpoints around `x`.
+	fn neighbours(&self, x: X) -> Neighbours<X, Y> {
+		assert!(N > 0, "The lookup table is empty");
+
+		// The keys of the closest probed breakpoints below and above `x`,
+		// i.e. at `lo - 1` and `hi`, so they need not be loaded again
+		let mut below = None;
+		let mut above = None;
+
+		let mut lo = 0;
+		let mut hi = N;
+		while lo < hi {
+			let mid = lo + (hi - lo) / 2;
+			let key = self.x_at(mid);
+
+			match key.cmp(&x) {
+				Ordering::Less => {
+					lo = mid + 1;
+					below = Some(key);
+				},
+				Ordering::Greater => {
+					hi = mid;
+					above = Some(key);
+				},
+				Ordering::Equal => return Neighbours::Hit(key, self.y_at(mid)),
+			}
+		}
+
+		match (below, above) {
+			// In between two breakpoints
+			(Some(x0), Some(x1)) => {
+				Neighbours::Between((x0, self.y_at(lo - 1)), (x1, self.y_at(lo)))
+			},
+			// Clamp to the first breakpoint
+			(None, Some(x1)) => Neighbours::Hit(x1, self.y_at(0)),
+			// Clamp to the last breakpoint
+			(Some(x0), None) => Neighbours::Hit(x0, self.y_at(N - 1)),
+			// The table is not empty, thus at least one breakpoint was probed
+			(None, None) => unreachable!(),
+		}
+	}
+}
+
+impl<X: Copy, Y: Copy, const N: usize> PmLut<X, Y, N> {
+	/// Loads just the `x` of the breakpoint at `idx`.
+	fn x_at(&self, idx: usize) -> X {
+		// SAFETY: the projection just computes the address of the field
+		unsafe { self.table.at(idx).project(|p| addr_of!((*p).0)) }.load()
+	}
+
+	/// Loads just the `y` of the breakpoint at `idx`.
+	fn y_at(&self, idx: usize) -> Y {
+		// SAFETY: the projection just computes the address of the field
+		unsafe { self.table.at(idx).project(|p| addr_of!((*p).1)) }.load()
+	}
+}
+
+/// The breakpoints found around some `x`.
+enum Neighbours<X, Y> {
+	/// The breakpoint to take as is, i.e. on an exact hit or when clamping
+	Hit(X, Y),
+	/// The two neighbouring breakpoints to interpolate between
+	Between((X, Y), (X, Y)),
+}
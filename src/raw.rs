@@ -18,11 +18,112 @@
 
 #[cfg(all(target_arch = "avr", not(doc)))]
 use core::arch::asm;
+use core::cmp::Ordering;
 use core::mem::size_of;
 use core::mem::MaybeUninit;
 
 use cfg_if::cfg_if;
 
+#[cfg(feature = "instrument")]
+pub mod instrument;
+
+
+
+/// The tag added to all progmem pointers in the strict host emulation.
+///
+/// With the "strict-host" crate feature, on 64-bit non-AVR targets, the
+/// [`ProgMem`](crate::wrapper::ProgMem) wrapper does not hold the plain
+/// address of its static, but the address offset by this tag, which points
+/// far outside of any mapped memory.
+/// Therefore, directly dereferencing such a pointer (which would be
+/// undefined behavior on AVR) crashes immediately, instead of silently
+/// working in tests.
+/// Only the functions of this module remove the tag again before reading.
+///
+/// The tag is a bit that is neither part of the user address space on
+/// x86-64 nor of the top byte ignored by AArch64.
+#[cfg(all(
+	feature = "strict-host",
+	not(target_arch = "avr"),
+	target_pointer_width = "64"
+))]
+pub const STRICT_HOST_TAG: usize = 1 << 55;
+
+/// Tag a progmem pointer for the strict host emulation.
+///
+/// See [`STRICT_HOST_TAG`], without the "strict-host" crate feature (or on
+/// AVR), the pointer is returned as is.
+pub(crate) const fn strict_host_tag<T>(p_addr: *const T) -> *const T {
+	#[cfg(all(
+		feature = "strict-host",
+		not(target_arch = "avr"),
+		target_pointer_width = "64"
+	))]
+	let p_addr = p_addr
+		.cast::<u8>()
+		.wrapping_add(STRICT_HOST_TAG)
+		.cast::<T>();
+
+	p_addr
+}
+
+/// Remove the tag of the strict host emulation from a progmem pointer.
+///
+/// This is the inverse of [`strict_host_tag`], pointers that are not tagged
+/// (e.g. ones given by the user) are returned as is.
+#[allow(dead_code)]
+pub(crate) fn strict_host_untag<T>(p_addr: *const T) -> *const T {
+	#[cfg(all(
+		feature = "strict-host",
+		not(target_arch = "avr"),
+		target_pointer_width = "64"
+	))]
+	let p_addr = if p_addr as usize & STRICT_HOST_TAG != 0 {
+		p_addr
+			.cast::<u8>()
+			.wrapping_sub(STRICT_HOST_TAG)
+			.cast::<T>()
+	} else {
+		p_addr
+	};
+
+	p_addr
+}
+
+
+/// The offset of the memory-mapped program memory in the data address space.
+///
+/// Newer AVR cores (e.g. the ATmega4809 or the ATtiny 0/1/2-series) map the
+/// entire program memory into the data address space, so it can be read via
+/// ordinary loads instead of the `lpm` instruction.
+/// The ATtiny 0/1/2-series map it at `0x8000` (the default of the
+/// "mapped-flash" crate feature), whereas the ATmega 0-series map it at
+/// `0x4000` (use the "mapped-flash-0x4000" crate feature).
+#[cfg(feature = "mapped-flash")]
+pub const MAPPED_FLASH_OFFSET: usize = if cfg!(feature = "mapped-flash-0x4000") {
+	0x4000
+} else {
+	0x8000
+};
+
+/// Translate a pointer into the progmem into the memory-mapped data domain.
+///
+/// The returned pointer points to the same value as `p_addr`, but within the
+/// memory-mapped window of the program memory
+/// (see [`MAPPED_FLASH_OFFSET`]), thus it may be dereferenced normally.
+///
+/// On all other architectures than AVR, the pointer is returned as is,
+/// since those use the normal data domain anyway.
+#[cfg(feature = "mapped-flash")]
+pub fn mapped_ptr<T>(p_addr: *const T) -> *const T {
+	cfg_if! {
+		if #[cfg(target_arch = "avr")] {
+			(p_addr as usize + MAPPED_FLASH_OFFSET) as *const T
+		} else {
+			strict_host_untag(p_addr)
+		}
+	}
+}
 
 
 /// Read a single byte from the progmem.
@@ -69,13 +170,53 @@ use cfg_if::cfg_if;
 /// [`read_slice`]: fn.read_slice.html
 /// [`read_value`]: fn.read_value.html
 ///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
 pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
+	#[cfg(feature = "instrument")]
+	instrument::record(Some(strict_host_untag(p_addr) as usize), 1);
+
 	cfg_if! {
-		if #[cfg(all(target_arch = "avr", not(doc)))] {
+		if #[cfg(all(target_arch = "avr", feature = "mapped-flash", not(doc)))] {
+			unsafe {
+				// SAFETY: the caller ensures that `p_addr` points into the
+				// progmem, which is memory-mapped in the data domain.
+				core::ptr::read(mapped_ptr(p_addr))
+			}
+
+		} else if #[cfg(all(target_arch = "avr", feature = "lpm-r0", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported!
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let res: u8;
+
+			// The inline assembly to read a single byte from given address
+			// using the implicit form of `lpm`, which always loads into r0.
+			unsafe {
+				asm!(
+					"
+						// Save r0, since we must restore it
+						mov {save}, r0
+
+						// Load the byte at Z implicitly into r0
+						lpm
+						mov {res}, r0
+
+						// Restore r0
+						mov r0, {save}
+					",
+					save = out(reg) _,
+					res = out(reg) res,
+					in("Z") p_addr,
+				);
+			}
+
+			res
+
+		} else if #[cfg(all(target_arch = "avr", not(doc)))] {
 			// Only addresses below the 64 KiB limit are supported!
 			// Apparently this is of no concern for architectures with true
 			// 16-bit pointers.
-			// TODO: switch to use the extended lpm instruction if >64k
+			// Data beyond that limit must be accessed via `read_byte_far`.
 			assert!(p_addr as usize <= u16::MAX as usize);
 
 			// Allocate a byte for the output (actually a single register r0
@@ -112,8 +253,8 @@ pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
 				// program domain on AVR.
 				//
 				// Consequently, it is sound to just dereference the pointer
-				// to data.
-				*p_addr
+				// to data (once any strict host tag is removed).
+				*strict_host_untag(p_addr)
 			}
 		} else {
 			// Special case, this neither possibly documentation on AVR, any
@@ -126,6 +267,27 @@ pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
 
 
 
+/// Compute the loop counter for copying `len` many `T`s.
+///
+/// The assembly loops use a 16-bit loop counter, which counts the number of
+/// bytes to copy.
+/// Thus, this function returns the size in bytes of `len` many `T`s, while
+/// asserting that it fits into the loop counter.
+///
+/// Notice, that on AVR this limit is of no practical concern, since the
+/// entire data memory is limited by 16-bit pointers anyway.
+fn loop_counter<T>(len: usize) -> u16 {
+	// Multiply the size in bytes of T with the given length
+	let size_bytes = size_of::<T>().checked_mul(len);
+
+	// Must not exceed 64 KiB
+	match size_bytes {
+		Some(size) if size <= u16::MAX as usize => size as u16,
+		_ => panic!("Can not load more than 64 KiB at once"),
+	}
+}
+
+
 /// Read an array of type `T` from progmem into data array.
 ///
 /// This function uses the optimized `read_asm_loop_raw` with a looped
@@ -134,7 +296,7 @@ pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
 ///
 /// # Safety
 ///
-/// This call is analog to `core::ptr::copy(p_addr, out, len as usize)` thus it
+/// This call is analog to `core::ptr::copy(p_addr, out, len)` thus it
 /// has the same basic requirements such as both pointers must be valid for
 /// dereferencing i.e. not dangling and both pointers must
 /// be valid to read or write, respectively, of `len` many elements of type `T`,
@@ -149,7 +311,7 @@ pub unsafe fn read_byte(p_addr: *const u8) -> u8 {
 /// `core::ptr::copy` and therefore the pointers must be aligned.
 ///
 #[cfg_attr(feature = "dev", inline(never))]
-unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
+unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: usize) {
 	// Here are the general requirements essentially required by the AVR-impl
 	// However, assume, the non-AVR version is only used in tests, it makes a
 	// lot of sens to ensure the AVR requirements are held up.
@@ -159,35 +321,79 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 		return;
 	}
 
-	// Get size in bytes of T
-	let size_type = size_of::<T>();
-	// Must not exceed 256 byte
-	assert!(size_type <= u8::MAX as usize);
-
-	// Multiply with the given length
-	let size_bytes = size_type * len as usize;
-	// Must still not exceed 256 byte
-	assert!(size_bytes <= u8::MAX as usize);
-	// Now its fine to cast down to u8
-	let size_bytes = size_bytes as u8;
+	// Get the total size in bytes, which is used as loop counter
+	let size_bytes = loop_counter::<T>(len);
 
 
 	cfg_if! {
-		if #[cfg(all(target_arch = "avr", not(doc)))] {
+		if #[cfg(all(target_arch = "avr", feature = "mapped-flash", not(doc)))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			unsafe {
+				// SAFETY: the caller ensures that `p_addr` points into the
+				// progmem, which is memory-mapped in the data domain.
+				core::ptr::copy_nonoverlapping(mapped_ptr(p_addr), out, len);
+			}
+
+		} else if #[cfg(all(target_arch = "avr", feature = "lpm-r0", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			// Split the loop counter, since `sbiw` might not be available
+			let [size_lo, size_hi] = size_bytes.to_le_bytes();
+
+			// Same loop as below, but using the implicit form of `lpm`,
+			// which always loads into r0 and does not increment Z.
+			// Therefore, Z is incremented manually, using only instructions
+			// available on all AVR cores.
+			unsafe {
+				asm!(
+					"
+						// Save r0, since we must restore it
+						mov {save}, r0
+
+						// load value from program memory at indirect Z into r0
+						lpm
+
+						// write r0 to data memory at indirect X
+						// and post-increment X by one
+						st X+, r0
+
+						// Increment Z by one (i.e. subtract -1)
+						subi r30, 0xFF
+						sbci r31, 0xFF
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						subi {lo}, 1
+						sbci {hi}, 0
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 7 instructions = 14 Byte
+						brne -14
+
+						// Restore r0
+						mov r0, {save}
+					",
+					save = out(reg) _,
+					// The loop counter in two upper registers (for `subi`)
+					lo = inout(reg_upper) size_lo => _,
+					hi = inout(reg_upper) size_hi => _,
+					// Input address in Z, gets modified
+					inout("Z") p_addr => _,
+					// Output address in X, gets modified
+					inout("X") out => _,
+				);
+			}
+
+		} else if #[cfg(all(target_arch = "avr", not(doc)))] {
 			// Only addresses below the 64 KiB limit are supported
 			// Apparently this is of no concern for architectures with true
 			// 16-bit pointers.
-			// TODO: switch to use the extended lpm instruction if >64k
+			// Data beyond that limit must be accessed via `read_value_far`.
 			assert!(p_addr as usize <= u16::MAX as usize);
 
-			// Some dummy variables so we can define "output" for our assembly.
-			// In fact, we do not have outputs, but need to modify the
-			// registers, thus we just mark them as "outputs".
-			let mut _a: u8;
-			let mut _b: *const ();
-			let mut _c: *mut ();
-			let mut _d: u8;
-
 			// A loop to read a slice of T from prog memory
 			// The prog memory address (addr) is stored in the 16-bit address
 			// register Z (since this is the default register for the `lpm`
@@ -211,9 +417,10 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 						// and post-increment X by one
 						st X+, {1}
 
-						// Decrement the loop counter in register $0 (size_bytes).
+						// Decrement the 16-bit loop counter in the register pair
+						// $0 (size_bytes).
 						// If zero has been reached the equality flag is set.
-						subi {0}, 1
+						sbiw {0}, 1
 
 						// Check whether the end has not been reached and if so jump back.
 						// The end is reached if $0 (size_bytes) == 0, i.e. equality flag
@@ -223,8 +430,9 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 						// Notice: 4 instructions = 8 Byte
 						brne -8
 					",
-					// Some register for counting the number of bytes, gets modified
-					inout(reg) size_bytes => _,
+					// Some register pair for counting the number of bytes,
+					// that supports `sbiw`, gets modified
+					inout(reg_iw) size_bytes => _,
 					// Some scratch register, just clobber
 					out(reg) _,
 					// Input address in Z, gets modified
@@ -249,8 +457,8 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 				// program domain on AVR.
 				//
 				// Consequently, it is sound to just dereference the pointers
-				// to data.
-				core::ptr::copy(p_addr, out, len as usize);
+				// to data (once any strict host tag is removed).
+				core::ptr::copy(strict_host_untag(p_addr), out, len);
 			}
 		} else {
 			// Special case, this neither possibly documentation on AVR, any
@@ -271,7 +479,7 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 ///
 /// # Safety
 ///
-/// This call is analog to `core::ptr::copy(p_addr, out, len as usize)` thus it
+/// This call is analog to `core::ptr::copy(p_addr, out, len)` thus it
 /// has the same basic requirements such as both pointers must be valid for
 /// dereferencing i.e. not dangling and both pointers must
 /// be valid to read or write, respectively, of `len` many elements of type `T`,
@@ -285,10 +493,17 @@ unsafe fn read_asm_loop_raw<T>(p_addr: *const T, out: *mut T, len: u8) {
 /// might be done actually use `core::ptr::copy` and therefore the pointers
 /// must be aligned.
 ///
-unsafe fn read_value_raw<T>(p_addr: *const T, out: *mut T, len: u8)
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+unsafe fn read_value_raw<T>(p_addr: *const T, out: *mut T, len: usize)
 where
 	T: Sized + Copy,
 {
+	#[cfg(feature = "instrument")]
+	instrument::record(
+		Some(strict_host_untag(p_addr) as usize),
+		len * size_of::<T>(),
+	);
+
 	unsafe {
 		// SAFETY: The caller must ensure the validity of the pointers
 		// and their domains.
@@ -355,14 +570,6 @@ where
 /// assert_eq!(b"World", &data);
 /// ```
 ///
-/// # Panics
-///
-/// This function panics, if the size of the value (i.e. `size_of::<T>()`)
-/// is beyond 255 bytes.
-/// However, this is currently just a implementation limitation, which may
-/// be lifted in the future.
-///
-///
 /// # Safety
 ///
 /// This call is analog to [`core::ptr::copy`] thus it
@@ -382,6 +589,7 @@ where
 /// [`read_slice`]: fn.read_slice.html
 ///
 #[cfg_attr(feature = "dev", inline(never))]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
 pub unsafe fn read_value<T>(p_addr: *const T) -> T
 where
 	T: Sized + Copy,
@@ -395,10 +603,6 @@ where
 	// still requires a `transmute` in the end.
 	let mut buffer = MaybeUninit::<T>::uninit();
 
-	let size = size_of::<T>();
-	// TODO add a local loop to process bigger chunks in 256 Byte blocks
-	assert!(size <= u8::MAX as usize);
-
 	let res: *mut T = buffer.as_mut_ptr();
 
 	unsafe {
@@ -418,3 +622,1194 @@ where
 		buffer.assume_init()
 	}
 }
+
+/// Read a slice of `T`s from progmem into the given data slice.
+///
+/// This function reads as many elements starting at `p_addr` as fit into
+/// `out`, i.e. `out.len()` many elements.
+/// In contrast to [`read_value`], this allows to fill an existing buffer in
+/// RAM directly, without first building a temporary value on the stack.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::raw::read_slice;
+/// use core::ptr::addr_of;
+///
+/// // This static must never be directly dereferenced/accessed!
+/// // So a `let data: [u8;11] = P_ARRAY;` is Undefined Behavior!!!
+/// /// Static bytes stored in progmem!
+/// #[link_section = ".progmem.data"]
+/// static P_ARRAY: [u8;11] = *b"Hello World";
+///
+/// // Some buffer that lives elsewhere
+/// let mut buffer = [0_u8; 16];
+///
+/// // Load the bytes from progmem directly into the front of that buffer
+/// // Here, it is sound, because due to the link_section it is indeed in the
+/// // program code memory.
+/// unsafe { read_slice(addr_of!(P_ARRAY).cast(), &mut buffer[..11]) };
+/// assert_eq!(b"Hello World", &buffer[..11]);
+/// ```
+///
+/// # Safety
+///
+/// This call is analog to [`core::ptr::copy`] thus it
+/// has the same basic requirements such as the pointer must be valid for
+/// dereferencing i.e. not dangling and the pointer must
+/// be valid to read `out.len()` many elements of type `T`,
+/// i.e. `out.len() * size_of::<T>()` bytes.
+///
+/// Additionally, `p_addr` must be a valid pointer into the program memory
+/// domain.
+///
+/// While the alignment is not strictly required for AVR, the non-AVR fallback
+/// might be actually using `core::ptr::copy` and therefore the pointers
+/// must be aligned.
+///
+/// # Panics
+///
+/// This function panics, if the size of `out` is 64 KiB or more.
+///
+/// [`read_value`]: fn.read_value.html
+///
+#[cfg_attr(feature = "dev", inline(never))]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn read_slice<T>(p_addr: *const T, out: &mut [T])
+where
+	T: Sized + Copy,
+{
+	let len = out.len();
+
+	unsafe {
+		// SAFETY: The soundness of this call is directly derived from the
+		// prerequisite as defined by the Safety section of this function.
+		//
+		// Additionally, `out` is a valid mutable slice and thus valid for
+		// writing `len` elements.
+		read_value_raw(p_addr, out.as_mut_ptr(), len);
+	}
+}
+
+
+/// Marker for types whose equality is the equality of their bytes.
+///
+/// This trait allows [`ProgMem::eq_slice`](crate::wrapper::ProgMem::eq_slice)
+/// to compare values in progmem directly byte by byte via
+/// [`compare_bytes`], without loading them first.
+///
+/// # Safety
+///
+/// Implementors must have no padding bytes, and two values must be equal
+/// (according to `PartialEq`) if and only if all their bytes are equal.
+/// For instance, this does not hold for floats (e.g. `0.0 == -0.0`).
+pub unsafe trait BytewiseEq: Copy + Eq {}
+
+macro_rules! impl_bytewise_eq {
+	($($t:ty),*) => {
+		$(
+			unsafe impl BytewiseEq for $t {
+				// SAFETY: primitive integers (and `bool` and `char`) have no
+				// padding, and are equal iff their bytes are equal.
+			}
+		)*
+	};
+}
+impl_bytewise_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+unsafe impl<T: BytewiseEq, const N: usize> BytewiseEq for [T; N] {
+	// SAFETY: arrays have no padding between their elements, and are equal
+	// iff all their elements are equal.
+}
+
+
+/// Compare bytes in progmem with bytes in RAM, like the `memcmp_P` of the
+/// avr-libc.
+///
+/// This function compares the `data.len()` many bytes starting at `p_addr`
+/// in the progmem with the given `data` lexicographically, returning the
+/// ordering of the progmem bytes relative to `data`.
+/// The bytes are streamed from progmem one at a time, stopping at the first
+/// mismatch, thus no buffer is needed.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::raw::compare_bytes;
+/// use core::cmp::Ordering;
+/// use core::ptr::addr_of;
+///
+/// // This static must never be directly dereferenced/accessed!
+/// /// Static bytes stored in progmem!
+/// #[link_section = ".progmem.data"]
+/// static P_ARRAY: [u8;5] = *b"Hello";
+///
+/// // Here, it is sound, because due to the link_section it is indeed in the
+/// // program code memory.
+/// let p_addr: *const u8 = addr_of!(P_ARRAY).cast();
+/// assert_eq!(Ordering::Equal, unsafe { compare_bytes(p_addr, b"Hello") });
+/// assert_eq!(Ordering::Less, unsafe { compare_bytes(p_addr, b"World") });
+/// assert_eq!(Ordering::Greater, unsafe { compare_bytes(p_addr, b"Hallo") });
+/// ```
+///
+/// # Safety
+///
+/// The pointer `p_addr` must be valid for reading `data.len()` many bytes
+/// in the program memory domain, see [`read_slice`].
+///
+/// # Panics
+///
+/// This function panics, if `data` is 64 KiB or larger.
+///
+#[cfg_attr(feature = "dev", inline(never))]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn compare_bytes(p_addr: *const u8, data: &[u8]) -> Ordering {
+	// SAFETY: the caller ensures the validity of `p_addr`
+	let (read, ord) = unsafe { compare_bytes_raw(p_addr, data) };
+
+	#[cfg(feature = "instrument")]
+	instrument::record(Some(strict_host_untag(p_addr) as usize), read);
+	#[cfg(not(feature = "instrument"))]
+	let _ = read;
+
+	ord
+}
+
+/// Compare two byte slices, returning the number of compared bytes
+/// (including the first mismatching one) and their ordering.
+#[cfg(any(feature = "mapped-flash", not(target_arch = "avr")))]
+fn compare_slices(pm: &[u8], data: &[u8]) -> (usize, Ordering) {
+	match pm.iter().zip(data).position(|(a, b)| a != b) {
+		Some(idx) => (idx + 1, pm[idx].cmp(&data[idx])),
+		None => (data.len(), Ordering::Equal),
+	}
+}
+
+/// The implementation of [`compare_bytes`].
+///
+/// Returns the number of bytes read from progmem and the resulting
+/// ordering.
+///
+/// # Safety
+///
+/// See [`compare_bytes`].
+unsafe fn compare_bytes_raw(p_addr: *const u8, data: &[u8]) -> (usize, Ordering) {
+	let len = data.len();
+
+	// Loop head check, just return for zero iterations
+	if len == 0 {
+		return (0, Ordering::Equal);
+	}
+
+	// Get the total size in bytes, which is used as loop counter
+	let size_bytes = loop_counter::<u8>(len);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", feature = "mapped-flash", not(doc)))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: the caller ensures that `p_addr` points into the
+			// progmem, which is memory-mapped in the data domain.
+			let pm = unsafe { core::slice::from_raw_parts(mapped_ptr(p_addr), len) };
+
+			compare_slices(pm, data)
+
+		} else if #[cfg(all(target_arch = "avr", feature = "lpm-r0", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let [size_lo, size_hi] = size_bytes.to_le_bytes();
+			let pm_byte: u8;
+			let data_byte: u8;
+			let rest_lo: u8;
+			let rest_hi: u8;
+
+			// Same loop as below, but using the implicit form of `lpm`,
+			// which always loads into r0 and does not increment Z.
+			unsafe {
+				asm!(
+					"
+						// Save r0, since we must restore it
+						mov {save}, r0
+
+						// load the progmem byte at Z into r0
+						lpm
+						mov {a}, r0
+
+						// load the data byte at X and post-increment X
+						ld {b}, X+
+
+						// Increment Z by one (i.e. subtract -1)
+						subi r30, 0xFF
+						sbci r31, 0xFF
+
+						// Stop at the first mismatch, skipping the rest of
+						// the loop.
+						// Notice: 3 instructions = 6 Byte
+						cp {a}, {b}
+						brne 6
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						subi {lo}, 1
+						sbci {hi}, 0
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 10 instructions = 20 Byte
+						brne -20
+
+						// Restore r0
+						mov r0, {save}
+					",
+					save = out(reg) _,
+					a = out(reg) pm_byte,
+					b = out(reg) data_byte,
+					// The loop counter in two upper registers (for `subi`)
+					lo = inout(reg_upper) size_lo => rest_lo,
+					hi = inout(reg_upper) size_hi => rest_hi,
+					// Input address in Z, gets modified
+					inout("Z") p_addr => _,
+					// Data address in X, gets modified
+					inout("X") data.as_ptr() => _,
+				);
+			}
+
+			let rest = u16::from_le_bytes([rest_lo, rest_hi]) as usize;
+			// On a mismatch, the counter has not been decremented for the
+			// last byte
+			(len - rest + (rest != 0) as usize, pm_byte.cmp(&data_byte))
+
+		} else if #[cfg(all(target_arch = "avr", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			// Data beyond that limit must be accessed via `read_value_far`.
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let pm_byte: u8;
+			let data_byte: u8;
+			let rest: u16;
+
+			// A loop comparing progmem bytes at Z with data bytes at X,
+			// analog to the loop of `read_asm_loop_raw`.
+			unsafe {
+				asm!(
+					"
+						// load the progmem byte at Z and post-increment Z
+						lpm {a}, Z+
+
+						// load the data byte at X and post-increment X
+						ld {b}, X+
+
+						// Stop at the first mismatch, skipping the rest of
+						// the loop.
+						// Notice: 2 instructions = 4 Byte
+						cp {a}, {b}
+						brne 4
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						sbiw {cnt}, 1
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 6 instructions = 12 Byte
+						brne -12
+					",
+					a = out(reg) pm_byte,
+					b = out(reg) data_byte,
+					// Some register pair for counting the number of bytes,
+					// that supports `sbiw`, gets modified
+					cnt = inout(reg_iw) size_bytes => rest,
+					// Input address in Z, gets modified
+					inout("Z") p_addr => _,
+					// Data address in X, gets modified
+					inout("X") data.as_ptr() => _,
+				);
+			}
+
+			let rest = rest as usize;
+			// On a mismatch, the counter has not been decremented for the
+			// last byte
+			(len - rest + (rest != 0) as usize, pm_byte.cmp(&data_byte))
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: we are not on AVR, thus all data must be in some
+			// sort of data domain (once any strict host tag is removed).
+			let pm = unsafe { core::slice::from_raw_parts(strict_host_untag(p_addr), len) };
+
+			compare_slices(pm, data)
+
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+
+/// Find the first occurrence of a byte in progmem, like the `memchr_P` of
+/// the avr-libc.
+///
+/// This function scans the `len` many bytes starting at `p_addr` in the
+/// progmem for the given `needle`, and returns the index of its first
+/// occurrence, if any.
+/// The bytes are streamed from progmem one at a time, stopping at the first
+/// match, thus no buffer is needed.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::raw::find_byte;
+/// use core::ptr::addr_of;
+///
+/// // This static must never be directly dereferenced/accessed!
+/// /// Static bytes stored in progmem!
+/// #[link_section = ".progmem.data"]
+/// static P_ARRAY: [u8;11] = *b"Hello World";
+///
+/// // Here, it is sound, because due to the link_section it is indeed in the
+/// // program code memory.
+/// let p_addr: *const u8 = addr_of!(P_ARRAY).cast();
+/// assert_eq!(Some(4), unsafe { find_byte(p_addr, 11, b'o') });
+/// assert_eq!(None, unsafe { find_byte(p_addr, 11, b'x') });
+/// ```
+///
+/// # Safety
+///
+/// The pointer `p_addr` must be valid for reading `len` many bytes in the
+/// program memory domain, see [`read_slice`].
+///
+/// # Panics
+///
+/// This function panics, if `len` is 64 KiB or larger.
+///
+#[cfg_attr(feature = "dev", inline(never))]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn find_byte(p_addr: *const u8, len: usize, needle: u8) -> Option<usize> {
+	// SAFETY: the caller ensures the validity of `p_addr`
+	let res = unsafe { find_byte_raw(p_addr, len, needle) };
+
+	#[cfg(feature = "instrument")]
+	instrument::record(
+		Some(strict_host_untag(p_addr) as usize),
+		res.map_or(len, |idx| idx + 1),
+	);
+
+	res
+}
+
+/// Find the last occurrence of a byte in progmem, like the `memrchr_P` of
+/// the avr-libc.
+///
+/// This function is analog to [`find_byte`], except that it scans the bytes
+/// backwards, starting at the end, and thus returns the index of the last
+/// occurrence of the `needle`, if any.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::raw::rfind_byte;
+/// use core::ptr::addr_of;
+///
+/// // This static must never be directly dereferenced/accessed!
+/// /// Static bytes stored in progmem!
+/// #[link_section = ".progmem.data"]
+/// static P_ARRAY: [u8;11] = *b"Hello World";
+///
+/// // Here, it is sound, because due to the link_section it is indeed in the
+/// // program code memory.
+/// let p_addr: *const u8 = addr_of!(P_ARRAY).cast();
+/// assert_eq!(Some(7), unsafe { rfind_byte(p_addr, 11, b'o') });
+/// assert_eq!(None, unsafe { rfind_byte(p_addr, 11, b'x') });
+/// ```
+///
+/// # Safety
+///
+/// The pointer `p_addr` must be valid for reading `len` many bytes in the
+/// program memory domain, see [`read_slice`].
+///
+/// # Panics
+///
+/// This function panics, if `len` is 64 KiB or larger.
+///
+#[cfg_attr(feature = "dev", inline(never))]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn rfind_byte(p_addr: *const u8, len: usize, needle: u8) -> Option<usize> {
+	// SAFETY: the caller ensures the validity of `p_addr`
+	let res = unsafe { rfind_byte_raw(p_addr, len, needle) };
+
+	#[cfg(feature = "instrument")]
+	instrument::record(
+		Some(strict_host_untag(p_addr) as usize),
+		res.map_or(len, |idx| len - idx),
+	);
+
+	res
+}
+
+/// The implementation of [`find_byte`].
+///
+/// # Safety
+///
+/// See [`find_byte`].
+unsafe fn find_byte_raw(p_addr: *const u8, len: usize, needle: u8) -> Option<usize> {
+	// Loop head check, just return for zero iterations
+	if len == 0 {
+		return None;
+	}
+
+	// Get the total size in bytes, which is used as loop counter
+	let size_bytes = loop_counter::<u8>(len);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", feature = "mapped-flash", not(doc)))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: the caller ensures that `p_addr` points into the
+			// progmem, which is memory-mapped in the data domain.
+			let pm = unsafe { core::slice::from_raw_parts(mapped_ptr(p_addr), len) };
+
+			pm.iter().position(|&b| b == needle)
+
+		} else if #[cfg(all(target_arch = "avr", feature = "lpm-r0", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let [size_lo, size_hi] = size_bytes.to_le_bytes();
+			let rest_lo: u8;
+			let rest_hi: u8;
+
+			// Same loop as below, but using the implicit form of `lpm`,
+			// which always loads into r0 and does not increment Z.
+			unsafe {
+				asm!(
+					"
+						// Save r0, since we must restore it
+						mov {save}, r0
+
+						// load the progmem byte at Z into r0
+						lpm
+
+						// Increment Z by one (i.e. subtract -1)
+						subi r30, 0xFF
+						sbci r31, 0xFF
+
+						// Stop at the first match, skipping the rest of
+						// the loop.
+						// Notice: 3 instructions = 6 Byte
+						cp r0, {needle}
+						breq 6
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						subi {lo}, 1
+						sbci {hi}, 0
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 8 instructions = 16 Byte
+						brne -16
+
+						// Restore r0
+						mov r0, {save}
+					",
+					save = out(reg) _,
+					needle = in(reg) needle,
+					// The loop counter in two upper registers (for `subi`)
+					lo = inout(reg_upper) size_lo => rest_lo,
+					hi = inout(reg_upper) size_hi => rest_hi,
+					// Input address in Z, gets modified
+					inout("Z") p_addr => _,
+				);
+			}
+
+			// On a match, the counter has not been decremented for the
+			// matching byte, otherwise it has reached zero.
+			match u16::from_le_bytes([rest_lo, rest_hi]) as usize {
+				0 => None,
+				rest => Some(len - rest),
+			}
+
+		} else if #[cfg(all(target_arch = "avr", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			// Data beyond that limit must be accessed via `read_value_far`.
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let rest: u16;
+
+			// A loop scanning the progmem bytes at Z for the needle,
+			// analog to the loop of `read_asm_loop_raw`.
+			unsafe {
+				asm!(
+					"
+						// load the progmem byte at Z and post-increment Z
+						lpm {b}, Z+
+
+						// Stop at the first match, skipping the rest of
+						// the loop.
+						// Notice: 2 instructions = 4 Byte
+						cp {b}, {needle}
+						breq 4
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						sbiw {cnt}, 1
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 5 instructions = 10 Byte
+						brne -10
+					",
+					b = out(reg) _,
+					needle = in(reg) needle,
+					// Some register pair for counting the number of bytes,
+					// that supports `sbiw`, gets modified
+					cnt = inout(reg_iw) size_bytes => rest,
+					// Input address in Z, gets modified
+					inout("Z") p_addr => _,
+				);
+			}
+
+			// On a match, the counter has not been decremented for the
+			// matching byte, otherwise it has reached zero.
+			match rest as usize {
+				0 => None,
+				rest => Some(len - rest),
+			}
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: we are not on AVR, thus all data must be in some
+			// sort of data domain (once any strict host tag is removed).
+			let pm = unsafe { core::slice::from_raw_parts(strict_host_untag(p_addr), len) };
+
+			pm.iter().position(|&b| b == needle)
+
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// The implementation of [`rfind_byte`].
+///
+/// # Safety
+///
+/// See [`rfind_byte`].
+unsafe fn rfind_byte_raw(p_addr: *const u8, len: usize, needle: u8) -> Option<usize> {
+	// Loop head check, just return for zero iterations
+	if len == 0 {
+		return None;
+	}
+
+	// Get the total size in bytes, which is used as loop counter
+	let size_bytes = loop_counter::<u8>(len);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", feature = "mapped-flash", not(doc)))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: the caller ensures that `p_addr` points into the
+			// progmem, which is memory-mapped in the data domain.
+			let pm = unsafe { core::slice::from_raw_parts(mapped_ptr(p_addr), len) };
+
+			pm.iter().rposition(|&b| b == needle)
+
+		} else if #[cfg(all(target_arch = "avr", feature = "lpm-r0", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let [size_lo, size_hi] = size_bytes.to_le_bytes();
+			let rest_lo: u8;
+			let rest_hi: u8;
+
+			// Same loop as below, but using the implicit form of `lpm`,
+			// which always loads into r0.
+			unsafe {
+				asm!(
+					"
+						// Save r0, since we must restore it
+						mov {save}, r0
+
+						// load the progmem byte at Z into r0
+						lpm
+
+						// Decrement Z by one
+						subi r30, 1
+						sbci r31, 0
+
+						// Stop at the first match, skipping the rest of
+						// the loop.
+						// Notice: 3 instructions = 6 Byte
+						cp r0, {needle}
+						breq 6
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						subi {lo}, 1
+						sbci {hi}, 0
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 8 instructions = 16 Byte
+						brne -16
+
+						// Restore r0
+						mov r0, {save}
+					",
+					save = out(reg) _,
+					needle = in(reg) needle,
+					// The loop counter in two upper registers (for `subi`)
+					lo = inout(reg_upper) size_lo => rest_lo,
+					hi = inout(reg_upper) size_hi => rest_hi,
+					// Address of the last byte in Z, gets modified
+					inout("Z") p_addr.wrapping_add(len - 1) => _,
+				);
+			}
+
+			// On a match, the counter has not been decremented for the
+			// matching byte, which is then the index of the match plus one.
+			match u16::from_le_bytes([rest_lo, rest_hi]) as usize {
+				0 => None,
+				rest => Some(rest - 1),
+			}
+
+		} else if #[cfg(all(target_arch = "avr", not(doc)))] {
+			// Only addresses below the 64 KiB limit are supported
+			// Data beyond that limit must be accessed via `read_value_far`.
+			assert!(p_addr as usize <= u16::MAX as usize);
+
+			let rest: u16;
+
+			// Same loop as in `find_byte_raw`, but starting at the last byte
+			// and decrementing Z, since there is no pre-decrementing `lpm`.
+			unsafe {
+				asm!(
+					"
+						// load the progmem byte at Z
+						lpm {b}, Z
+
+						// Decrement Z by one
+						sbiw r30, 1
+
+						// Stop at the first match, skipping the rest of
+						// the loop.
+						// Notice: 2 instructions = 4 Byte
+						cp {b}, {needle}
+						breq 4
+
+						// Decrement the 16-bit loop counter, if zero has been
+						// reached, the equality flag is set.
+						sbiw {cnt}, 1
+
+						// Jump back to the `lpm`, if the end is not reached
+						// Notice: 6 instructions = 12 Byte
+						brne -12
+					",
+					b = out(reg) _,
+					needle = in(reg) needle,
+					// Some register pair for counting the number of bytes,
+					// that supports `sbiw`, gets modified
+					cnt = inout(reg_iw) size_bytes => rest,
+					// Address of the last byte in Z, gets modified
+					inout("Z") p_addr.wrapping_add(len - 1) => _,
+				);
+			}
+
+			// On a match, the counter has not been decremented for the
+			// matching byte, which is then the index of the match plus one.
+			match rest as usize {
+				0 => None,
+				rest => Some(rest - 1),
+			}
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			// SAFETY: we are not on AVR, thus all data must be in some
+			// sort of data domain (once any strict host tag is removed).
+			let pm = unsafe { core::slice::from_raw_parts(strict_host_untag(p_addr), len) };
+
+			pm.iter().rposition(|&b| b == needle)
+
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+
+
+/// An address into the program memory that may lie beyond the 64 KiB limit.
+///
+/// Normal Rust pointers on AVR are just 16-bit wide and thus can only address
+/// the lower 64 KiB of the program memory, which is all that the `lpm`
+/// instruction can reach anyway.
+/// However, AVR chips with more than 64 KiB of flash (such as the ATmega2560)
+/// offer the `elpm` instruction, which uses the `RAMPZ` register as
+/// additional third address byte.
+/// Therefore, such "far" addresses are represented by a `u32` on AVR
+/// (with only the lower 24-bits being used).
+///
+/// On all other architectures, this type is just a `usize` holding the address
+/// of the value in the normal data domain.
+///
+/// Far addresses can be obtained by the [`far_addr_of`](crate::far_addr_of)
+/// macro.
+pub type FarAddr = FarAddrRepr;
+
+/// The integer type of [`FarAddr`] on AVR.
+#[cfg(target_arch = "avr")]
+type FarAddrRepr = u32;
+
+/// The integer type of [`FarAddr`] on all other architectures.
+#[cfg(not(target_arch = "avr"))]
+type FarAddrRepr = usize;
+
+
+/// Get the far progmem address of a `static`.
+///
+/// This macro is the far counterpart of [`addr_of!`](core::ptr::addr_of),
+/// it yields the full [`FarAddr`](crate::raw::FarAddr) of the given `static`,
+/// even if that `static` is located beyond the 64 KiB limit of normal
+/// pointers.
+///
+/// Since the address of such a `static` can not be represented by a normal
+/// Rust pointer on AVR, this macro has to use some inline assembly to let the
+/// linker fill in the full address.
+/// Consequently, on AVR, your crate needs to enable the
+/// `asm_experimental_arch` Rust feature (i.e.
+/// `#![feature(asm_experimental_arch)]`) to use this macro.
+///
+/// Notice, that there is no need to use this macro for `static`s created via
+/// the [`progmem!`](crate::progmem) macro, since its `far` rule does this
+/// for you.
+///
+///
+/// # Example
+///
+/// ```
+/// use avr_progmem::far_addr_of;
+/// use avr_progmem::raw::read_byte_far;
+///
+/// // This static must never be directly dereferenced/accessed!
+/// /// Static byte stored anywhere in progmem!
+/// #[link_section = ".progmemx.data"]
+/// static P_BYTE: u8 = b'A';
+///
+/// // Get the full address of the static
+/// let addr = far_addr_of!(P_BYTE);
+///
+/// // Load the byte from progmem
+/// // Here, it is sound, because due to the link_section it is indeed in the
+/// // program code memory.
+/// let data: u8 = unsafe { read_byte_far(addr) };
+/// assert_eq!(b'A', data);
+/// ```
+///
+#[macro_export]
+macro_rules! far_addr_of {
+	($static:path) => {{
+		#[cfg(target_arch = "avr")]
+		let addr: $crate::raw::FarAddr = {
+			let lo: u8;
+			let hi: u8;
+			let hh: u8;
+
+			unsafe {
+				// Let the linker fill in the full 24-bit address of the
+				// static, byte by byte.
+				::core::arch::asm!(
+					"ldi {lo}, lo8({addr})",
+					"ldi {hi}, hi8({addr})",
+					"ldi {hh}, hh8({addr})",
+					lo = out(reg_upper) lo,
+					hi = out(reg_upper) hi,
+					hh = out(reg_upper) hh,
+					addr = sym $static,
+					options(pure, nomem, nostack),
+				);
+			}
+
+			u32::from_le_bytes([lo, hi, hh, 0])
+		};
+
+		#[cfg(not(target_arch = "avr"))]
+		let addr: $crate::raw::FarAddr = ::core::ptr::addr_of!($static) as usize;
+
+		addr
+	}};
+}
+
+
+/// Read a single byte from anywhere in the progmem.
+///
+/// This is the far variant of [`read_byte`], it accepts a [`FarAddr`] and
+/// uses the AVR `elpm` instruction, thus it can also access data beyond the
+/// 64 KiB limit.
+/// The `RAMPZ` register is set for the read and restored afterwards.
+///
+/// Notice, that only AVR devices with more than 64 KiB of flash support the
+/// `elpm` instruction, this function must not be used on any other AVR
+/// device.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::far_addr_of;
+/// use avr_progmem::raw::read_byte_far;
+///
+/// /// Static byte stored anywhere in progmem!
+/// #[link_section = ".progmemx.data"]
+/// static P_BYTE: u8 = b'A';
+///
+/// let data: u8 = unsafe { read_byte_far(far_addr_of!(P_BYTE)) };
+/// assert_eq!(b'A', data);
+/// ```
+///
+///
+/// # Safety
+///
+/// The given address must be valid in the program domain.
+///
+/// Typically, only addresses of or into statics that are defined to be stored
+/// into progmem are valid, e.g. statics that are attributed with
+/// `#[link_section = ".progmem.data"]` or
+/// `#[link_section = ".progmemx.data"]`.
+///
+/// On all architectures other than AVR, the address must be the address of
+/// some valid `u8` in the normal data domain instead.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn read_byte_far(p_addr: FarAddr) -> u8 {
+	#[cfg(feature = "instrument")]
+	instrument::record(far_record_addr(p_addr), 1);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			let [lo, hi, hh, _] = p_addr.to_le_bytes();
+			let z = u16::from_le_bytes([lo, hi]);
+
+			let res: u8;
+
+			unsafe {
+				asm!(
+					// Save the current `RAMPZ` (I/O address 0x3B)
+					"in {tmp}, 0x3B",
+					// Set `RAMPZ` to the highest byte of the address
+					"out 0x3B, {hh}",
+					// Load the byte from the extended address RAMPZ:Z,
+					// i.e. `elpm r18, Z`, which is given as its raw encoding,
+					// because the assembler rejects `elpm` for devices
+					// without it, even if this function is never used there.
+					".word 0x9126",
+					// Restore `RAMPZ`
+					"out 0x3B, {tmp}",
+					tmp = out(reg) _,
+					hh = in(reg) hh,
+					out("r18") res,
+					in("Z") z,
+				);
+			}
+
+			res
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain, and the caller guarantees that the
+				// address is the address of some valid `u8`.
+				*(p_addr as *const u8)
+			}
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+
+/// Read an array of type `T` from anywhere in progmem into data array.
+///
+/// This is the far variant of `read_asm_loop_raw` using the `elpm`
+/// instruction.
+///
+///
+/// # Safety
+///
+/// Same as for `read_asm_loop_raw`, except that `p_addr` is a far address.
+///
+unsafe fn read_asm_loop_far_raw<T>(p_addr: FarAddr, out: *mut T, len: usize) {
+	// Loop head check, just return for zero iterations
+	if len == 0 || size_of::<T>() == 0 {
+		return;
+	}
+
+	// Get the total size in bytes, which is used as loop counter
+	let size_bytes = loop_counter::<T>(len);
+
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			let [lo, hi, hh, _] = p_addr.to_le_bytes();
+			let z = u16::from_le_bytes([lo, hi]);
+
+			// Same loop as in `read_asm_loop_raw`, except that we use `elpm`
+			// instead of `lpm`.
+			// Notice that the post-increment of `elpm` also carries over
+			// into `RAMPZ`, so this loop may even cross a 64 KiB boundary.
+			unsafe {
+				asm!(
+					"
+						// save the current RAMPZ (I/O address 0x3B) into the
+						// scratch register, and set it to the highest address byte
+						in {1}, 0x3B
+						out 0x3B, {2}
+
+						// load value from program memory at indirect RAMPZ:Z into
+						// r18 and post-increment RAMPZ:Z by one, i.e.
+						// `elpm r18, Z+` as raw encoding, see `read_byte_far`
+						.word 0x9127
+
+						// write r18 to data memory at indirect X
+						// and post-increment X by one
+						st X+, r18
+
+						// Decrement the 16-bit loop counter and jump back to
+						// `elpm`, unless zero has been reached.
+						// Notice: 4 instructions = 8 Byte
+						sbiw {0}, 1
+						brne -8
+
+						// Restore RAMPZ
+						out 0x3B, {1}
+					",
+					// Some register pair for counting the number of bytes,
+					// that supports `sbiw`, gets modified
+					inout(reg_iw) size_bytes => _,
+					// Register to hold the original RAMPZ
+					out(reg) _,
+					// Highest address byte
+					in(reg) hh,
+					// The fixed scratch register of `elpm`, just clobber
+					out("r18") _,
+					// Input address in Z, gets modified
+					inout("Z") z => _,
+					// Output address in X, gets modified
+					inout("X") out => _,
+				);
+			}
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			// Ignore the unused vars:
+			let _ = size_bytes;
+
+			unsafe {
+				// SAFETY: we are not on AVR, thus all data must be in some
+				// sort of data domain, and the address is a plain data
+				// address.
+				core::ptr::copy(p_addr as *const T, out, len);
+			}
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+
+/// Read a single `T` from anywhere in progmem and return it by value.
+///
+/// This is the far variant of [`read_value`], it accepts a [`FarAddr`] and
+/// uses the AVR `elpm` instruction, thus it can also access data beyond the
+/// 64 KiB limit.
+///
+/// Notice, that only AVR devices with more than 64 KiB of flash support the
+/// `elpm` instruction, this function must not be used on any other AVR
+/// device.
+///
+/// ## Example
+///
+/// ```
+/// use avr_progmem::far_addr_of;
+/// use avr_progmem::raw::read_value_far;
+///
+/// /// Static bytes stored anywhere in progmem!
+/// #[link_section = ".progmemx.data"]
+/// static P_ARRAY: [u8;11] = *b"Hello World";
+///
+/// let data: [u8;11] = unsafe { read_value_far(far_addr_of!(P_ARRAY)) };
+/// assert_eq!(b"Hello World", &data);
+/// ```
+///
+/// # Safety
+///
+/// This call is analog to [`read_value`], except that `p_addr` must be a
+/// valid far address of a `T` in the program memory domain.
+///
+/// On all architectures other than AVR, the address must be the address of
+/// some valid `T` in the normal data domain instead.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub unsafe fn read_value_far<T>(p_addr: FarAddr) -> T
+where
+	T: Sized + Copy,
+{
+	#[cfg(feature = "instrument")]
+	instrument::record(far_record_addr(p_addr), size_of::<T>());
+
+	let mut buffer = MaybeUninit::<T>::uninit();
+
+	let res: *mut T = buffer.as_mut_ptr();
+
+	unsafe {
+		// SAFETY: The soundness of this call is directly derived from the
+		// prerequisite as defined by the Safety section of this function.
+		read_asm_loop_far_raw(p_addr, res, 1);
+	}
+
+	unsafe {
+		// SAFETY: After `read_asm_loop_far_raw` returned, it wrote an entire
+		// `T` into the `res` pointer, which is baked by this `buffer`.
+		buffer.assume_init()
+	}
+}
+
+
+/// The size in bytes of the stack buffer used by the far comparing and
+/// searching functions.
+const FAR_BUFFER_SIZE: usize = 32;
+
+/// Returns the address of a far read as recorded by the instrumentation.
+///
+/// On AVR, only addresses within the lower 64 KiB can be attributed to a
+/// watched `static`, all others are only accounted in the total.
+#[cfg(feature = "instrument")]
+fn far_record_addr(p_addr: FarAddr) -> Option<usize> {
+	cfg_if! {
+		if #[cfg(target_arch = "avr")] {
+			if p_addr <= FarAddr::from(u16::MAX) {
+				Some(p_addr as usize)
+			} else {
+				None
+			}
+		} else {
+			Some(p_addr)
+		}
+	}
+}
+
+/// Read a slice of type `T` from anywhere in progmem into the given buffer.
+///
+/// This is the far variant of [`read_slice`], which is used by the `ProgMem`
+/// wrappers with the "elpm" crate feature.
+///
+/// # Safety
+///
+/// Same as for [`read_slice`], except that `p_addr` is a far address.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub(crate) unsafe fn read_slice_far<T>(p_addr: FarAddr, out: &mut [T])
+where
+	T: Sized + Copy,
+{
+	#[cfg(feature = "instrument")]
+	instrument::record(far_record_addr(p_addr), core::mem::size_of_val(out));
+
+	// SAFETY: the caller ensures that `p_addr` is valid for `out.len()`
+	// many `T`s, and `out` is valid for writing them.
+	unsafe { read_asm_loop_far_raw(p_addr, out.as_mut_ptr(), out.len()) }
+}
+
+/// Compare bytes anywhere in progmem with bytes in RAM.
+///
+/// This is the far variant of [`compare_bytes`], it loads the bytes in
+/// small chunks onto the stack and stops at the first mismatching chunk.
+///
+/// # Safety
+///
+/// Same as for [`compare_bytes`], except that `p_addr` is a far address.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub(crate) unsafe fn compare_bytes_far(p_addr: FarAddr, data: &[u8]) -> Ordering {
+	let mut buffer = [0_u8; FAR_BUFFER_SIZE];
+	let mut addr = p_addr;
+
+	for chunk in data.chunks(FAR_BUFFER_SIZE) {
+		let pm = &mut buffer[..chunk.len()];
+
+		// SAFETY: the caller ensures that `p_addr` is valid for as many bytes
+		// as `data` has, and the chunks stay within them.
+		unsafe { read_slice_far(addr, pm) };
+
+		match (*pm).cmp(chunk) {
+			Ordering::Equal => addr += chunk.len() as FarAddr,
+			ord => return ord,
+		}
+	}
+
+	Ordering::Equal
+}
+
+/// Find the first occurrence of a byte anywhere in progmem.
+///
+/// This is the far variant of [`find_byte`], it loads the bytes in small
+/// chunks onto the stack and stops at the first chunk containing the byte.
+///
+/// # Safety
+///
+/// Same as for [`find_byte`], except that `p_addr` is a far address.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub(crate) unsafe fn find_byte_far(p_addr: FarAddr, len: usize, needle: u8) -> Option<usize> {
+	let mut buffer = [0_u8; FAR_BUFFER_SIZE];
+	let mut start = 0;
+
+	while start < len {
+		let pm = &mut buffer[..FAR_BUFFER_SIZE.min(len - start)];
+
+		// SAFETY: the caller ensures that `p_addr` is valid for `len` many
+		// bytes, and the chunk stays within them.
+		unsafe { read_slice_far(p_addr + start as FarAddr, pm) };
+
+		if let Some(idx) = pm.iter().position(|&b| b == needle) {
+			return Some(start + idx);
+		}
+
+		start += pm.len();
+	}
+
+	None
+}
+
+/// Find the last occurrence of a byte anywhere in progmem.
+///
+/// This is the far variant of [`rfind_byte`], analog to [`find_byte_far`]
+/// but scanning the chunks backwards.
+///
+/// # Safety
+///
+/// Same as for [`rfind_byte`], except that `p_addr` is a far address.
+///
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub(crate) unsafe fn rfind_byte_far(p_addr: FarAddr, len: usize, needle: u8) -> Option<usize> {
+	let mut buffer = [0_u8; FAR_BUFFER_SIZE];
+	let mut end = len;
+
+	while end > 0 {
+		let start = end - FAR_BUFFER_SIZE.min(end);
+		let pm = &mut buffer[..(end - start)];
+
+		// SAFETY: the caller ensures that `p_addr` is valid for `len` many
+		// bytes, and the chunk stays within them.
+		unsafe { read_slice_far(p_addr + start as FarAddr, pm) };
+
+		if let Some(idx) = pm.iter().rposition(|&b| b == needle) {
+			return Some(start + idx);
+		}
+
+		end = start;
+	}
+
+	None
+}
@@ -0,0 +1,466 @@
+//! Instrumentation of the progmem accesses
+//!
+//! This module is only available with the "instrument" crate feature.
+//! It counts every read of the program memory done by the [raw](crate::raw)
+//! functions (and thus also by all the wrappers), that is the number of
+//! reads and the number of bytes read.
+//! This allows to spot hot data, e.g. a `PmString` that is iterated char by
+//! char in a tight loop, instead of guessing it from cycle counts alone.
+//!
+//! Besides the global [`total`], the counters can be broken down per
+//! progmem static, by registering them via [`watch`].
+//! On all architectures other than AVR, each watched static additionally
+//! records the call sites (i.e. the code location of e.g. the
+//! `ProgMem::load` call) from which it was read.
+//! The call sites are tracked through the `raw` functions,
+//! `ProgMem::load`, `ProgMem::load_at` (for arrays), as well as the `PmIter`
+//! and `PmChars` iterators, any other method records its own location within
+//! this crate instead.
+//!
+//! Notice, that the counting itself is not free, thus it skews the timing
+//! of the instrumented code, and should not be enabled in production.
+//!
+//!
+//! # Example
+//!
+//! ```rust
+//! use avr_progmem::progmem;
+//! use avr_progmem::raw::instrument;
+//!
+//! progmem! {
+//!     static progmem ARR: [u8; 4] = [1, 2, 3, 4];
+//!     static progmem string TEXT = "Hello";
+//! }
+//!
+//! instrument::watch("ARR", &ARR);
+//! instrument::watch("TEXT", TEXT.as_bytes());
+//!
+//! let _ = ARR.load();
+//! let _ = ARR.load_at(2);
+//! let _ = TEXT.chars().count();
+//!
+//! let arr = instrument::counters(&ARR).unwrap();
+//! assert_eq!(2, arr.reads);
+//! assert_eq!(5, arr.bytes);
+//!
+//! let text = instrument::counters(TEXT.as_bytes()).unwrap();
+//! assert_eq!(5, text.reads);
+//! assert_eq!(5, text.bytes);
+//!
+//! assert_eq!(7, instrument::total().reads);
+//!
+//! // Print all counters
+//! println!("{}", instrument::report());
+//! ```
+//!
+
+
+#[cfg(all(target_arch = "avr", not(doc)))]
+use core::arch::asm;
+use core::cell::UnsafeCell;
+use core::fmt;
+#[cfg(not(target_arch = "avr"))]
+use core::panic::Location;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::AtomicBool;
+#[cfg(not(target_arch = "avr"))]
+use core::sync::atomic::Ordering;
+
+use cfg_if::cfg_if;
+
+use super::strict_host_untag;
+use crate::wrapper::ProgMem;
+
+
+
+/// The maximum number of progmem statics that can be watched at once.
+pub const SLOTS: usize = 8;
+
+/// The maximum number of distinct call sites recorded per watched static.
+///
+/// Reads from further call sites are still counted for the static, but
+/// their location is not recorded.
+#[cfg(not(target_arch = "avr"))]
+pub const CALL_SITES: usize = 4;
+
+
+/// Access counters of the progmem.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Counters {
+	/// The number of read operations, e.g. one per `ProgMem::load` call.
+	pub reads: u32,
+	/// The total number of bytes read.
+	pub bytes: u32,
+}
+
+impl Counters {
+	/// Account a single read of `bytes` many bytes.
+	fn add(&mut self, bytes: usize) {
+		self.reads = self.reads.saturating_add(1);
+		self.bytes = self.bytes.saturating_add(bytes as u32);
+	}
+}
+
+/// A code location from which a watched static was read.
+///
+/// Only available on non-AVR architectures.
+#[cfg(not(target_arch = "avr"))]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct CallSite {
+	/// The location of the call, e.g. of `ProgMem::load`.
+	pub location: &'static Location<'static>,
+	/// The accesses from this location.
+	pub counters: Counters,
+}
+
+/// The counters of a watched progmem static.
+///
+/// See [`watch`] and [`for_each`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Entry {
+	/// The name given to [`watch`].
+	pub name: &'static str,
+	/// The accesses of this static.
+	pub counters: Counters,
+	/// The locations from which this static was read.
+	///
+	/// Only available on non-AVR architectures.
+	#[cfg(not(target_arch = "avr"))]
+	pub call_sites: [Option<CallSite>; CALL_SITES],
+}
+
+/// A watched address range.
+#[derive(Clone, Copy)]
+struct Slot {
+	start: usize,
+	end: usize,
+	entry: Entry,
+}
+
+/// The global instrumentation state.
+struct State {
+	total: Counters,
+	slots: [Option<Slot>; SLOTS],
+}
+
+/// A `Sync` wrapper around the global `State`.
+///
+/// Only accessed via `with_state`.
+struct Global(UnsafeCell<State>);
+
+unsafe impl Sync for Global {
+	// SAFETY: the inner state is only accessed via `with_state`, which
+	// ensures mutual exclusion.
+}
+
+static STATE: Global = Global(UnsafeCell::new(State {
+	total: Counters {
+		reads: 0,
+		bytes: 0,
+	},
+	slots: [None; SLOTS],
+}));
+
+/// The spin lock guarding the `STATE` on non-AVR architectures.
+#[cfg(not(target_arch = "avr"))]
+static LOCK: AtomicBool = AtomicBool::new(false);
+
+/// Execute `f` with exclusive access to the global state.
+///
+/// On AVR, this disables interrupts while executing `f`, otherwise it uses a
+/// simple spin lock. Thus `f` must be short and must not access the state
+/// again (i.e. it must not read the progmem).
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+	cfg_if! {
+		if #[cfg(all(target_arch = "avr", not(doc)))] {
+			let sreg: u8;
+
+			unsafe {
+				// Save the status register and disable interrupts
+				asm!(
+					"
+						in {sreg}, 0x3F
+						cli
+					",
+					sreg = out(reg) sreg,
+				);
+			}
+
+			// SAFETY: we have a single core, and interrupts are disabled,
+			// thus we have exclusive access.
+			let res = f(unsafe { &mut *STATE.0.get() });
+
+			unsafe {
+				// Restore the status register, i.e. the interrupt flag
+				asm!(
+					"out 0x3F, {sreg}",
+					sreg = in(reg) sreg,
+				);
+			}
+
+			res
+
+		} else if #[cfg(not(target_arch = "avr"))] {
+			while LOCK
+				.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+				.is_err()
+			{
+				core::hint::spin_loop();
+			}
+
+			// SAFETY: we hold the lock, thus we have exclusive access.
+			let res = f(unsafe { &mut *STATE.0.get() });
+
+			LOCK.store(false, Ordering::Release);
+
+			res
+
+		} else {
+			unreachable!("You should not execute code, compiled in `doc` mode");
+		}
+	}
+}
+
+/// Returns the data address range of the given progmem static.
+fn range_of<T>(pm: &ProgMem<T>) -> (usize, usize) {
+	let start = strict_host_untag(pm.as_ptr()) as usize;
+	(start, start + core::mem::size_of::<T>())
+}
+
+
+/// Record a read of `bytes` many bytes at the given address.
+///
+/// The address is `None` for far reads beyond the reach of normal pointers,
+/// which are only accounted in the total.
+#[cfg_attr(not(target_arch = "avr"), track_caller)]
+pub(crate) fn record(addr: Option<usize>, bytes: usize) {
+	#[cfg(not(target_arch = "avr"))]
+	let caller = Location::caller();
+
+	with_state(|state| {
+		state.total.add(bytes);
+
+		let addr = match addr {
+			Some(addr) => addr,
+			None => return,
+		};
+
+		let slot = state
+			.slots
+			.iter_mut()
+			.flatten()
+			.find(|slot| slot.start <= addr && addr < slot.end);
+
+		if let Some(slot) = slot {
+			slot.entry.counters.add(bytes);
+
+			#[cfg(not(target_arch = "avr"))]
+			{
+				let site = slot.entry.call_sites.iter_mut().find(|site| {
+					match site {
+						Some(site) => site.location == caller,
+						None => true,
+					}
+				});
+
+				if let Some(site) = site {
+					site.get_or_insert(CallSite {
+						location: caller,
+						counters: Counters::default(),
+					})
+					.counters
+					.add(bytes);
+				}
+			}
+		}
+	})
+}
+
+
+/// Watch the given progmem static under the given name.
+///
+/// From now on, all reads of `pm` (including reads of single elements, e.g.
+/// via `ProgMem::load_at`) are counted separately for it.
+/// Watching an already watched static just renames it.
+///
+/// Returns `false`, if there are already [`SLOTS`] many statics watched.
+pub fn watch<T>(name: &'static str, pm: &ProgMem<T>) -> bool {
+	let (start, end) = range_of(pm);
+
+	with_state(|state| {
+		if let Some(slot) = state.slots.iter_mut().flatten().find(|s| s.start == start) {
+			slot.entry.name = name;
+			return true;
+		}
+
+		match state.slots.iter_mut().find(|s| s.is_none()) {
+			Some(free) => {
+				*free = Some(Slot {
+					start,
+					end,
+					entry: Entry {
+						name,
+						counters: Counters::default(),
+						#[cfg(not(target_arch = "avr"))]
+						call_sites: [None; CALL_SITES],
+					},
+				});
+				true
+			},
+			None => false,
+		}
+	})
+}
+
+/// Returns the counters of the given static, if it is watched.
+pub fn counters<T>(pm: &ProgMem<T>) -> Option<Counters> {
+	let (start, _) = range_of(pm);
+
+	with_state(|state| {
+		state
+			.slots
+			.iter()
+			.flatten()
+			.find(|slot| slot.start == start)
+			.map(|slot| slot.entry.counters)
+	})
+}
+
+/// Returns the counters of all progmem reads, watched or not.
+pub fn total() -> Counters {
+	with_state(|state| state.total)
+}
+
+/// Reset all counters to zero, but keep watching the same statics.
+pub fn reset() {
+	with_state(|state| {
+		state.total = Counters::default();
+
+		for slot in state.slots.iter_mut().flatten() {
+			slot.entry.counters = Counters::default();
+
+			#[cfg(not(target_arch = "avr"))]
+			{
+				slot.entry.call_sites = [None; CALL_SITES];
+			}
+		}
+	})
+}
+
+/// Reset all counters and stop watching any statics.
+pub fn clear() {
+	with_state(|state| {
+		state.total = Counters::default();
+		state.slots = [None; SLOTS];
+	})
+}
+
+/// Call `f` for each watched static.
+///
+/// Each entry is a snapshot, thus `f` may freely read the progmem, e.g. to
+/// print the entry.
+pub fn for_each(mut f: impl FnMut(&Entry)) {
+	for idx in 0..SLOTS {
+		if let Some(entry) = entry(idx) {
+			f(&entry)
+		}
+	}
+}
+
+/// Returns a snapshot of the entry in the given slot.
+fn entry(idx: usize) -> Option<Entry> {
+	with_state(|state| state.slots[idx].map(|slot| slot.entry))
+}
+
+/// Returns a printable report of all counters.
+///
+/// The report implements `Display` and `uDisplay` (with the "ufmt" crate
+/// feature), listing the total and the counters of each watched static.
+pub fn report() -> Report {
+	Report {
+		_private: (),
+	}
+}
+
+
+/// A printable report of all counters.
+///
+/// See [`report`].
+#[derive(Debug)]
+pub struct Report {
+	_private: (),
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		let total = total();
+		writeln!(fmt, "total: {} reads, {} bytes", total.reads, total.bytes)?;
+
+		for idx in 0..SLOTS {
+			let entry = match entry(idx) {
+				Some(entry) => entry,
+				None => continue,
+			};
+
+			writeln!(
+				fmt,
+				"{}: {} reads, {} bytes",
+				entry.name, entry.counters.reads, entry.counters.bytes
+			)?;
+
+			#[cfg(not(target_arch = "avr"))]
+			for site in entry.call_sites.iter().flatten() {
+				writeln!(
+					fmt,
+					"  at {}: {} reads, {} bytes",
+					site.location, site.counters.reads, site.counters.bytes
+				)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Report {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		let total = total();
+		ufmt::uwrite!(fmt, "total: {} reads, {} bytes\n", total.reads, total.bytes)?;
+
+		for idx in 0..SLOTS {
+			let entry = match entry(idx) {
+				Some(entry) => entry,
+				None => continue,
+			};
+
+			ufmt::uwrite!(
+				fmt,
+				"{}: {} reads, {} bytes\n",
+				entry.name,
+				entry.counters.reads,
+				entry.counters.bytes
+			)?;
+
+			#[cfg(not(target_arch = "avr"))]
+			for site in entry.call_sites.iter().flatten() {
+				ufmt::uwrite!(
+					fmt,
+					"  at {}:{}: {} reads, {} bytes\n",
+					site.location.file(),
+					site.location.line(),
+					site.counters.reads,
+					site.counters.bytes
+				)?;
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -21,6 +21,10 @@
 //! However, for the time being, this module offers as a convenient workaround:
 //! * [`LoadedString`] a simple UTF-8 encoded sized byte array
 //! * [`PmString`] a UTF-8 encoded sized byte array in progmem similar to [`ProgMem`].
+//! * [`PmStr`] the length-erased counterpart of a [`PmString`], which can be
+//!   used without being generic over the length of the string.
+//! * [`PmFarString`] the far counterpart of [`PmString`] similar to [`ProgMemFar`].
+//! * [`PmStringTable`] a table of UTF-8 encoded strings of different lengths in progmem.
 //!
 //!
 //! # Working with Strings
@@ -157,7 +161,7 @@
 //!
 //! You can also use arbitrary `&str`-yielding expression, including loading
 //! huge strings from files, just don't use `PmString::load` nor `progmem_str`
-//! with huge strings (because if it is bigger than 255 bytes, it will panic).
+//! with huge strings (because it would load the entire string onto the stack).
 //!
 //! ```rust
 //! use avr_progmem::progmem;
@@ -175,12 +179,19 @@
 //!
 
 
+use core::cmp::Ordering;
 use core::convert::TryFrom;
 use core::fmt;
+use core::iter::FusedIterator;
 use core::ops::Deref;
+use core::ops::Range;
 
-use crate::wrapper::PmIter;
+use derivative::Derivative;
+
+use crate::wrapper::PmFarIter;
+use crate::wrapper::PmWrapperIter;
 use crate::wrapper::ProgMem;
+use crate::wrapper::ProgMemFar;
 
 
 pub(crate) mod from_slice;
@@ -192,6 +203,124 @@ mod validations;
 #[derive(Debug, Clone)]
 pub struct InvalidLengthError;
 
+/// Indicates that some bytes are not valid UTF-8.
+///
+/// This is the equivalent of [`core::str::Utf8Error`] (which can not be
+/// constructed outside of the core library), it is returned when validating
+/// bytes in progmem, such as by [`PmString::try_from_progmem`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Utf8Error {
+	valid_up_to: usize,
+	error_len: Option<u8>,
+}
+
+impl Utf8Error {
+	/// Returns the index up to which the bytes are valid UTF-8.
+	///
+	/// See [`core::str::Utf8Error::valid_up_to`].
+	pub const fn valid_up_to(&self) -> usize {
+		self.valid_up_to
+	}
+
+	/// Returns the length of the invalid byte sequence, or `None` if the
+	/// bytes end in the middle of a sequence.
+	///
+	/// See [`core::str::Utf8Error::error_len`].
+	pub const fn error_len(&self) -> Option<usize> {
+		match self.error_len {
+			Some(len) => Some(len as usize),
+			None => None,
+		}
+	}
+
+	/// Converts the error of the core library, same as the `From` impl, but
+	/// usable in a `const` context.
+	const fn from_core(err: core::str::Utf8Error) -> Self {
+		Utf8Error {
+			valid_up_to: err.valid_up_to(),
+			error_len: match err.error_len() {
+				Some(len) => Some(len as u8),
+				None => None,
+			},
+		}
+	}
+}
+
+impl From<core::str::Utf8Error> for Utf8Error {
+	fn from(err: core::str::Utf8Error) -> Self {
+		Self::from_core(err)
+	}
+}
+
+impl fmt::Display for Utf8Error {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		match self.error_len {
+			Some(len) => {
+				write!(
+					fmt,
+					"invalid utf-8 sequence of {} bytes from index {}",
+					len, self.valid_up_to
+				)
+			},
+			None => {
+				write!(
+					fmt,
+					"incomplete utf-8 byte sequence from index {}",
+					self.valid_up_to
+				)
+			},
+		}
+	}
+}
+
+/// Checks whether the given bytes are valid UTF-8, in a `const` context.
+///
+/// This is the `const` equivalent of the validation of
+/// [`PmString::try_from_progmem`], e.g. to validate bytes at compile-time,
+/// before they are put into progmem.
+/// Also see [`expect_utf8`].
+pub const fn validate_utf8(bytes: &[u8]) -> Result<(), Utf8Error> {
+	match core::str::from_utf8(bytes) {
+		Ok(_) => Ok(()),
+		Err(err) => Err(Utf8Error::from_core(err)),
+	}
+}
+
+/// Converts the given bytes into a `str` in a `const` context, panicking if
+/// they are not valid UTF-8.
+///
+/// This allows to put a progmem string from arbitrary bytes (e.g. from
+/// `include_bytes!`) via the [`progmem`](crate::progmem) macro, which are
+/// validated at compile-time, i.e. invalid UTF-8 is a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::string::expect_utf8;
+///
+/// progmem! {
+///     static progmem string TEXT = expect_utf8(include_bytes!("../examples/test_text.txt"));
+/// }
+/// ```
+///
+/// While invalid UTF-8 is rejected:
+///
+/// ```compile_fail
+/// use avr_progmem::progmem;
+/// use avr_progmem::string::expect_utf8;
+///
+/// progmem! {
+///     static progmem string TEXT = expect_utf8(&[b'a', 0xFF]);
+/// }
+/// ```
+pub const fn expect_utf8(bytes: &[u8]) -> &str {
+	match core::str::from_utf8(bytes) {
+		Ok(s) => s,
+		Err(_) => panic!("The bytes are not valid UTF-8"),
+	}
+}
+
 
 /// A string stored as byte array.
 ///
@@ -327,8 +456,125 @@ impl<const N: usize> ufmt::uDisplay for LoadedString<N> {
 }
 
 
+/// A string in progmem
+///
+/// This is a wrapper around UTF-8 encoded bytes in progmem, which comes in
+/// two forms, depending on the byte storage `B`:
+///
+/// * [`PmString<N>`], i.e. a `PmStr<[u8; N]>`, which wraps a byte array of
+///   statically known length as created by the [`progmem`](crate::progmem)
+///   macro,
+/// * `PmStr`, i.e. a `PmStr<[u8]>`, which wraps a byte slice, whose length is
+///   only known at runtime.
+///
+/// The latter is the length-erased counterpart of the former, which allows to
+/// write functions that accept any progmem string without being generic
+/// over its length (thus avoiding the code bloat of monomorphization), and to
+/// store strings of different lengths in the same struct or array.
+/// A `PmString<N>` can be converted into a `PmStr` via its
+/// [`as_pm_str`](PmString::as_pm_str) method or the respective `From` impl,
+/// and if the "unsize" crate feature is enabled, it can even be coerced
+/// directly, just like a `ProgMem<[u8; N]>` into a `ProgMem<[u8]>`.
+///
+/// Both forms offer a lazy `chars` iterator that loads just one char at a
+/// time, and can be printed via their `Display` and `uDisplay` impls, which
+/// use that iterator.
+///
+///
+/// # Safety
+///
+/// This type is a wrapper around [`ProgMem`], thus it any value of this type
+/// must be placed in program memory.
+/// See the [`ProgMem`] safety section for more details.
+///
+/// Additionally to the [`ProgMem`] contract, the bytes wrapped by this
+/// struct must be valid UTF-8.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::string::PmStr;
+///
+/// progmem! {
+///     static progmem string HELLO = "Hello";
+///     static progmem string WORLD = "大賢者";
+/// }
+///
+/// // Not generic over the length of the string
+/// fn print(s: PmStr) -> String {
+///     format!("{} ({} bytes)", s, s.len())
+/// }
+///
+/// assert_eq!("Hello (5 bytes)", print(HELLO.as_pm_str()));
+/// assert_eq!("大賢者 (9 bytes)", print(WORLD.into()));
+///
+/// // Strings of different lengths in the same array
+/// let both: [PmStr; 2] = [HELLO.as_pm_str(), WORLD.as_pm_str()];
+/// assert_eq!(14, both.iter().map(|s| s.len()).sum::<usize>());
+/// ```
+///
+//
+// SAFETY: this struct must not be publicly constructible
+#[non_exhaustive]
+//
+// Its just a pointer type, thus copy, clone & debug are fine (none of them
+// will access the progmem, that's what `Display` is for).
+#[derive(Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct PmStr<B: ?Sized = [u8]> {
+	/// The inner UTF-8 string as bytes in progmem.
+	///
+	/// # Safety
+	///
+	/// Must be valid UTF-8.
+	pm_utf8: ProgMem<B>,
+}
+
+/// Implement `uDebug` by hand, analog to `ProgMem`.
+#[cfg(feature = "ufmt")]
+impl<B: ?Sized> ufmt::uDebug for PmStr<B> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("PmStr")?
+			.field("pm_utf8", &self.pm_utf8)?
+			.finish()
+	}
+}
+
+/// Allows coercing a `PmString<N>` to a `PmStr`.
+///
+/// This is a generalization of the [`as_pm_str`](PmString::as_pm_str)
+/// method.
+///
+/// # Examples
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::string::PmStr;
+///
+/// progmem!{
+///     static progmem string TEXT = "Hello";
+/// }
+///
+/// // Coerced to a length-erased string, just like that.
+/// let s: PmStr = TEXT;
+/// assert_eq!(5, s.len());
+/// ```
+#[cfg(feature = "unsize")]
+impl<T: ?Sized, U: ?Sized> core::ops::CoerceUnsized<PmStr<U>> for PmStr<T> where
+	T: core::marker::Unsize<U>
+{
+}
+
+
 /// A byte string in progmem
 ///
+/// This is a [`PmStr`] wrapping a byte array of statically known length.
+///
 /// Not to be confused with a [`LoadedString`].
 /// A `LoadedString` is a simple wrapper around a byte array (`[u8;N]`) that
 /// derefs to `str`, and should be used in RAM.
@@ -347,12 +593,7 @@ impl<const N: usize> ufmt::uDisplay for LoadedString<N> {
 ///
 /// # Safety
 ///
-/// This type is a wrapper around [`ProgMem`], thus it any value of this type
-/// must be placed in program memory.
-/// See the [`ProgMem`] safety section for more details.
-///
-/// Additionally to the [`ProgMem`] contract, the byte array wrapped by this
-/// struct must be valid UTF-8.
+/// See the [`PmStr`] safety section.
 ///
 ///
 /// # Example
@@ -376,26 +617,10 @@ impl<const N: usize> ufmt::uDisplay for LoadedString<N> {
 /// assert_eq!("dai 大賢者 kenja", &*loaded)
 /// ```
 ///
-//
-//
-// SAFETY: this struct must not be publicly constructible
-#[non_exhaustive]
-//
-// Its just a pointer type, thus copy, clone & debug are fine (none of them
-// will access the progmem, that's what `Display` is for).
-#[derive(Copy, Clone, Debug)]
-// Also impl `uDebug` if enabled.
-#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
-pub struct PmString<const N: usize> {
-	/// The inner UTF-8 string as byte array in progmem.
-	///
-	/// # Safety
-	///
-	/// Must be valid UTF-8.
-	pm_utf8_array: ProgMem<[u8; N]>,
-}
+pub type PmString<const N: usize> = PmStr<[u8; N]>;
+
 
-impl<const N: usize> PmString<N> {
+impl<const N: usize> PmStr<[u8; N]> {
 	/// Creates a new byte array from the given string
 	///
 	/// You are encouraged to use the [`progmem`](crate::progmem) macro instead.
@@ -407,22 +632,63 @@ impl<const N: usize> PmString<N> {
 	pub const unsafe fn new(pm: ProgMem<[u8; N]>) -> Self {
 		// SAFETY: the caller ensures that the bytes are valid UTF-8
 		Self {
-			pm_utf8_array: pm,
+			pm_utf8: pm,
 		}
 	}
 
-	/// Loads the entire string into RAM
+	/// Creates a new string from the given progmem bytes, after checking
+	/// that they are valid UTF-8.
 	///
-	/// # Panics
+	/// This is the safe counterpart of [`new`](Self::new), it validates the
+	/// bytes one `char` at a time directly in progmem, without loading them as
+	/// whole.
+	/// For bytes known at compile-time, consider validating them before they
+	/// are put into progmem instead, see [`expect_utf8`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	/// use avr_progmem::string::PmString;
+	///
+	/// progmem! {
+	///     static progmem GOOD: [u8; 5] = *b"Hello";
+	///     static progmem BAD: [u8; 4] = [b'a', 0xFF, b'b', b'c'];
+	/// }
 	///
-	/// This method panics, if the size of the value (i.e. `N`) is beyond 255
-	/// bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
+	/// let text = PmString::try_from_progmem(GOOD).unwrap();
+	/// assert_eq!("Hello", format!("{}", text));
+	///
+	/// let err = PmString::try_from_progmem(BAD).unwrap_err();
+	/// assert_eq!(1, err.valid_up_to());
+	/// assert_eq!(Some(1), err.error_len());
+	/// ```
+	pub fn try_from_progmem(pm: ProgMem<[u8; N]>) -> Result<Self, Utf8Error> {
+		let mut bytes = pm.iter();
+		let mut valid_up_to = 0;
+
+		loop {
+			match validations::try_next_code_point(&mut bytes) {
+				Ok(Some((_, width))) => valid_up_to += width,
+				Ok(None) => break,
+				Err(error_len) => {
+					return Err(Utf8Error {
+						valid_up_to,
+						error_len,
+					})
+				},
+			}
+		}
+
+		// SAFETY: we just checked that the bytes are valid UTF-8
+		Ok(unsafe { Self::new(pm) })
+	}
+
+	/// Loads the entire string into RAM
 	///
 	/// If you have a very large string, consider using the lazy
 	/// [`chars`](Self::chars) iterator that accesses the string by one char at
-	/// a time and thus does not have such a limitation.
+	/// a time and thus only requires a few bytes of stack memory.
 	///
 	pub fn load(&self) -> LoadedString<N> {
 		let array = self.load_bytes();
@@ -437,13 +703,6 @@ impl<const N: usize> PmString<N> {
 
 	/// Loads the entire string as byte array into RAM
 	///
-	/// # Panics
-	///
-	/// This method panics, if the size of the value (i.e. `[u8; N]`) is beyond
-	/// 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
-	///
 	/// If you have a very large string, consider using the lazy
 	/// [`chars`](Self::chars) iterator or the respective byte iterator
 	/// (via `as_bytes().iter()`).
@@ -453,7 +712,82 @@ impl<const N: usize> PmString<N> {
 
 	/// Returns the underlying progmem byte array.
 	pub fn as_bytes(&self) -> &ProgMem<[u8; N]> {
-		&self.pm_utf8_array
+		&self.pm_utf8
+	}
+
+	/// Returns the length of the string in bytes.
+	pub fn len(&self) -> usize {
+		N
+	}
+
+	/// Returns whether the string is empty.
+	pub fn is_empty(&self) -> bool {
+		N == 0
+	}
+
+	/// Erases the length of this string, turning it into a [`PmStr`].
+	///
+	/// Notice, if you enable the "unsize" crate feature, you can directly
+	/// coerce a `PmString<N>` into a `PmStr`.
+	pub const fn as_pm_str(&self) -> PmStr {
+		// SAFETY: the contract on `Self` guarantees us that the bytes are
+		// valid UTF-8
+		unsafe { PmStr::<[u8]>::new(self.pm_utf8.as_slice()) }
+	}
+
+	/// Get a reference to the string, without loading it.
+	///
+	/// This is only available with the "mapped-flash" crate feature,
+	/// see [`ProgMem::as_ref`].
+	#[cfg(feature = "mapped-flash")]
+	pub fn as_str(&self) -> &'static str {
+		let bytes: &'static [u8; N] = self.pm_utf8.as_ref();
+
+		// SAFETY: The contract on `Self` guarantees us that we have UTF-8
+		unsafe { core::str::from_utf8_unchecked(bytes) }
+	}
+
+	/// Returns whether this string is equal to the given one.
+	///
+	/// This is the equivalent of `strcmp_P` of the avr-libc, it compares the
+	/// bytes directly in progmem without loading the string into RAM.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "Hello World";
+	/// }
+	///
+	/// assert!(TEXT.eq_str("Hello World"));
+	/// assert!(!TEXT.eq_str("Hello"));
+	/// ```
+	pub fn eq_str(&self, s: &str) -> bool {
+		self.pm_utf8.eq_slice(s.as_bytes())
+	}
+
+	/// Compares this string lexicographically with the given one.
+	///
+	/// This method is analog to `str::cmp`, but compares the bytes directly
+	/// in progmem, stopping at the first mismatch.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	/// use core::cmp::Ordering;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "Bar";
+	/// }
+	///
+	/// assert_eq!(Ordering::Greater, TEXT.cmp_str("Baa"));
+	/// assert_eq!(Ordering::Less, TEXT.cmp_str("Foo"));
+	/// ```
+	pub fn cmp_str(&self, s: &str) -> Ordering {
+		self.as_pm_str().cmp_str(s)
 	}
 
 	/// Lazily iterate over the `char`s of the string.
@@ -461,69 +795,1118 @@ impl<const N: usize> PmString<N> {
 	/// This function is analog to [`ProgMem::iter`], except it performs UTF-8
 	/// parsing and returns the `char`s of this string, thus it is more similar
 	/// to [`str::chars`].
-	pub fn chars(&self) -> PmChars<N> {
-		PmChars::new(self)
+	///
+	/// Like the latter, the iterator can also be used from the back, e.g. to
+	/// find the last `char`s without decoding the entire string.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "dai 大賢者";
+	/// }
+	///
+	/// let mut chars = TEXT.chars();
+	/// assert_eq!(Some('d'), chars.next());
+	/// assert_eq!(Some('者'), chars.next_back());
+	/// assert_eq!("賢大 ia", chars.rev().collect::<String>());
+	/// ```
+	pub fn chars(&self) -> PmChars {
+		self.as_pm_str().chars()
 	}
-}
 
-impl<const N: usize> fmt::Display for PmString<N> {
-	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-		for c in self.chars() {
-			write!(fmt, "{}", c)?
-		}
-		Ok(())
+	/// Lazily iterate over the `char`s of the string and their byte
+	/// positions.
+	///
+	/// This function is analog to [`str::char_indices`], decoding one `char`
+	/// at a time, just like [`chars`](Self::chars).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "a大b";
+	/// }
+	///
+	/// let mut iter = TEXT.char_indices();
+	/// assert_eq!(Some((0, 'a')), iter.next());
+	/// assert_eq!(Some((1, '大')), iter.next());
+	/// assert_eq!(Some((4, 'b')), iter.next());
+	/// assert_eq!(None, iter.next());
+	/// ```
+	pub fn char_indices(&self) -> PmCharIndices {
+		self.as_pm_str().char_indices()
 	}
-}
 
-#[cfg(feature = "ufmt")]
-impl<const N: usize> ufmt::uDisplay for PmString<N> {
-	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
-	where
-		W: ufmt::uWrite,
-	{
-		for c in self.chars() {
-			ufmt::uwrite!(fmt, "{}", c)?
-		}
-		Ok(())
+	/// Returns whether the string starts with the given pattern.
+	///
+	/// This method is analog to [`str::starts_with`], but compares the bytes
+	/// directly in progmem, without loading the string.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "AT+RST";
+	/// }
+	///
+	/// assert!(TEXT.starts_with("AT+"));
+	/// assert!(TEXT.ends_with("RST"));
+	/// assert!(!TEXT.starts_with("RST"));
+	/// ```
+	pub fn starts_with(&self, pat: &str) -> bool {
+		self.as_pm_str().starts_with(pat)
 	}
-}
 
+	/// Returns whether the string ends with the given pattern.
+	///
+	/// This method is analog to [`str::ends_with`], but compares the bytes
+	/// directly in progmem, without loading the string.
+	pub fn ends_with(&self, pat: &str) -> bool {
+		self.as_pm_str().ends_with(pat)
+	}
 
-/// An iterator over a [`PmString`]
-///
-/// # Safety
-///
-/// The inner byte iterator of this struct must yield valid UTF-8 sequence.
-#[non_exhaustive] // SAFETY: this struct must not be publicly constructible
-pub struct PmChars<'a, const N: usize> {
-	/// The inner byte iterator
+	/// Returns the byte index of the first occurrence of the given pattern.
 	///
-	/// # Safety
+	/// This method is analog to [`str::find`], it scans the bytes directly in
+	/// progmem for the first byte of the pattern (see
+	/// [`ProgMem::find_byte`]), and compares the rest of the pattern at each
+	/// candidate, without loading the string.
 	///
-	/// Must yield valid UTF-8 sequences.
-	bytes: PmIter<'a, u8, N>,
-}
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "dai 大賢者 kenja";
+	/// }
+	///
+	/// assert_eq!(Some(4), TEXT.find("大賢"));
+	/// assert_eq!(Some(14), TEXT.find("kenja"));
+	/// assert_eq!(None, TEXT.find("sage"));
+	/// assert!(TEXT.contains("者 k"));
+	/// ```
+	pub fn find(&self, pat: &str) -> Option<usize> {
+		self.as_pm_str().find(pat)
+	}
 
-impl<'a, const N: usize> PmChars<'a, N> {
-	pub fn new(pm: &'a PmString<N>) -> Self {
-		// SAFETY: the contract on PmString guarantees us that it wraps
-		// valid UTF-8, thus its byte iterator will yield valid UTF-8
-		PmChars {
-			bytes: pm.pm_utf8_array.iter(),
-		}
+	/// Returns whether the string contains the given pattern.
+	///
+	/// See [`find`](Self::find).
+	pub fn contains(&self, pat: &str) -> bool {
+		self.as_pm_str().contains(pat)
 	}
-}
 
-impl<'a, const N: usize> Iterator for PmChars<'a, N> {
-	type Item = char;
+	/// Lazily iterate over the sub-strings separated by the given char.
+	///
+	/// This method is analog to [`str::split`], except that the sub-strings
+	/// are yielded as [`PmStr`], i.e. views into the progmem, instead of
+	/// being loaded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string CSV = "red,green,,blue";
+	/// }
+	///
+	/// let mut iter = CSV.split(',');
+	/// assert!(iter.next().unwrap().eq_str("red"));
+	/// assert!(iter.next().unwrap().eq_str("green"));
+	/// assert!(iter.next().unwrap().eq_str(""));
+	/// assert!(iter.next().unwrap().eq_str("blue"));
+	/// assert!(iter.next().is_none());
+	/// ```
+	pub fn split(&self, sep: char) -> PmSplit {
+		self.as_pm_str().split(sep)
+	}
 
-	fn next(&mut self) -> Option<Self::Item> {
-		unsafe {
-			// SAFETY: the contract on `Self` struct guarantees us that we only
-			// get valid UTF-8 sequences
-			validations::next_code_point(&mut self.bytes)
-		}
-		.map(|u| core::char::from_u32(u).unwrap())
+	/// Lazily iterate over the lines of the string.
+	///
+	/// This method is analog to [`str::lines`], i.e. lines end with either
+	/// `\n` or `\r\n`, and the final line ending is optional.
+	/// A bare `\r`, that is not followed by a `\n`, is kept in the line.
+	/// The lines are yielded as [`PmStr`], i.e. views into the progmem,
+	/// instead of being loaded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string HELP = "Usage:\r\n  help\n\n  reset\n";
+	/// }
+	///
+	/// let lines: Vec<String> = HELP.lines().map(|l| format!("{}", l)).collect();
+	/// assert_eq!(lines, ["Usage:", "  help", "", "  reset"]);
+	///
+	/// // A carriage return is only stripped in front of a line feed
+	/// progmem!{
+	///     static progmem string CR = "a\r\n\nb\r";
+	/// }
+	///
+	/// let lines: Vec<String> = CR.lines().map(|l| format!("{}", l)).collect();
+	/// assert_eq!(lines, ["a", "", "b\r"]);
+	/// ```
+	pub fn lines(&self) -> PmLines {
+		self.as_pm_str().lines()
+	}
+
+	/// Returns the number of `char`s in the string.
+	///
+	/// This is analog to `chars().count()`, but it just counts the bytes
+	/// starting a `char` instead of decoding them.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "dai 大賢者 kenja";
+	/// }
+	///
+	/// assert_eq!(19, TEXT.len());
+	/// assert_eq!(13, TEXT.char_count());
+	/// ```
+	pub fn char_count(&self) -> usize {
+		self.as_pm_str().char_count()
+	}
+
+	/// Checks whether the byte at the given index is a char boundary.
+	///
+	/// This method is analog to [`str::is_char_boundary`], i.e. the start and
+	/// the end of the string are char boundaries, too.
+	pub fn is_char_boundary(&self, idx: usize) -> bool {
+		self.as_pm_str().is_char_boundary(idx)
+	}
+
+	/// Returns the sub-string at the given byte range, without loading it.
+	///
+	/// This method is analog to [`str::get`], it returns `None`, if the range
+	/// is out of bounds or does not start or end on a char boundary.
+	/// The sub-string is a [`PmStr`], i.e. a view into the progmem, which
+	/// can be printed via `Display` or `uDisplay` without loading it as whole.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "dai 大賢者 kenja";
+	/// }
+	///
+	/// assert_eq!("大賢者", format!("{}", TEXT.get(4..13).unwrap()));
+	///
+	/// // Not on a char boundary
+	/// assert!(TEXT.get(4..6).is_none());
+	/// // Out of bounds
+	/// assert!(TEXT.get(14..20).is_none());
+	/// ```
+	pub fn get(&self, range: Range<usize>) -> Option<PmStr> {
+		self.as_pm_str().get(range)
+	}
+
+	/// Divides the string into two at the given byte index, without loading
+	/// it.
+	///
+	/// This method is analog to [`str::split_at`].
+	///
+	/// # Panics
+	///
+	/// This method panics, if `mid` is not on a char boundary or beyond the
+	/// end of the string.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem string TEXT = "dai 大賢者 kenja";
+	/// }
+	///
+	/// let (head, tail) = TEXT.split_at(4);
+	/// assert!(head.eq_str("dai "));
+	/// assert!(tail.eq_str("大賢者 kenja"));
+	/// ```
+	pub fn split_at(&self, mid: usize) -> (PmStr, PmStr) {
+		self.as_pm_str().split_at(mid)
+	}
+}
+
+/// Same as the `Display` of [`PmStr`], to which this delegates
+impl<const N: usize> fmt::Display for PmStr<[u8; N]> {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.as_pm_str(), fmt)
+	}
+}
+
+/// Same as the `uDisplay` of [`PmStr`], to which this delegates
+#[cfg(feature = "ufmt")]
+impl<const N: usize> ufmt::uDisplay for PmStr<[u8; N]> {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		ufmt::uDisplay::fmt(&self.as_pm_str(), fmt)
+	}
+}
+
+
+
+/// Same as [`PmString::try_from_progmem`]
+impl<const N: usize> TryFrom<ProgMem<[u8; N]>> for PmString<N> {
+	type Error = Utf8Error;
+
+	fn try_from(pm: ProgMem<[u8; N]>) -> Result<Self, Self::Error> {
+		Self::try_from_progmem(pm)
+	}
+}
+
+/// Same as [`PmString::as_pm_str`]
+impl<const N: usize> From<PmString<N>> for PmStr {
+	fn from(s: PmString<N>) -> Self {
+		s.as_pm_str()
+	}
+}
+
+impl PmStr<[u8]> {
+	/// Creates a new string slice from the given progmem bytes
+	///
+	/// # Safety
+	///
+	/// This function is only sound to call, if the value is
+	/// is a valid `ProgMem`, and the underlying byte slice contains valid UTF-8.
+	pub const unsafe fn new(pm: ProgMem<[u8]>) -> Self {
+		// SAFETY: the caller ensures that the bytes are valid UTF-8
+		Self {
+			pm_utf8: pm,
+		}
+	}
+
+	/// Returns the length of the string in bytes.
+	pub fn len(&self) -> usize {
+		self.pm_utf8.len()
+	}
+
+	/// Returns whether the string is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the underlying progmem byte slice.
+	pub fn as_bytes(&self) -> &ProgMem<[u8]> {
+		&self.pm_utf8
+	}
+
+	/// Lazily iterate over the `char`s of the string.
+	///
+	/// See [`PmString::chars`].
+	pub fn chars(&self) -> PmChars {
+		PmChars::new(self)
+	}
+
+	/// Lazily iterate over the `char`s of the string and their byte
+	/// positions.
+	///
+	/// See [`PmString::char_indices`].
+	pub fn char_indices(&self) -> PmCharIndices {
+		PmCharIndices {
+			chars: self.chars(),
+			offset: 0,
+		}
+	}
+
+	/// Returns whether this string is equal to the given one.
+	///
+	/// See [`PmString::eq_str`].
+	pub fn eq_str(&self, s: &str) -> bool {
+		self.pm_utf8.eq_slice(s.as_bytes())
+	}
+
+	/// Compares this string lexicographically with the given one.
+	///
+	/// See [`PmString::cmp_str`].
+	pub fn cmp_str(&self, s: &str) -> Ordering {
+		self.pm_utf8.cmp_slice(s.as_bytes())
+	}
+
+	/// Returns whether the string starts with the given pattern.
+	///
+	/// See [`PmString::starts_with`].
+	pub fn starts_with(&self, pat: &str) -> bool {
+		pat.len() <= self.len()
+			&& self
+				.pm_utf8
+				.sub_slice(0..pat.len())
+				.eq_slice(pat.as_bytes())
+	}
+
+	/// Returns whether the string ends with the given pattern.
+	///
+	/// See [`PmString::ends_with`].
+	pub fn ends_with(&self, pat: &str) -> bool {
+		pat.len() <= self.len()
+			&& self
+				.pm_utf8
+				.sub_slice(self.len() - pat.len()..self.len())
+				.eq_slice(pat.as_bytes())
+	}
+
+	/// Returns the byte index of the first occurrence of the given pattern.
+	///
+	/// See [`PmString::find`].
+	pub fn find(&self, pat: &str) -> Option<usize> {
+		let pat = pat.as_bytes();
+		let first = match pat.first() {
+			Some(first) => *first,
+			None => return Some(0),
+		};
+
+		let mut start = 0;
+		while let Some(idx) = self.pm_utf8.sub_slice(start..self.len()).find_byte(first) {
+			let pos = start + idx;
+			if pat.len() > self.len() - pos {
+				return None;
+			}
+			// Since UTF-8 is self-synchronizing, any match of a valid UTF-8
+			// pattern lies on char boundaries.
+			if self.pm_utf8.sub_slice(pos..pos + pat.len()).eq_slice(pat) {
+				return Some(pos);
+			}
+			start = pos + 1;
+		}
+
+		None
+	}
+
+	/// Returns whether the string contains the given pattern.
+	///
+	/// See [`PmString::contains`].
+	pub fn contains(&self, pat: &str) -> bool {
+		self.find(pat).is_some()
+	}
+
+	/// Lazily iterate over the sub-strings separated by the given char.
+	///
+	/// See [`PmString::split`].
+	pub fn split(&self, sep: char) -> PmSplit {
+		let mut sep_utf8 = [0; 4];
+		let sep_len = sep.encode_utf8(&mut sep_utf8).len();
+
+		PmSplit {
+			remainder: Some(*self),
+			sep_utf8,
+			sep_len,
+		}
+	}
+
+	/// Lazily iterate over the lines of the string.
+	///
+	/// See [`PmString::lines`].
+	pub fn lines(&self) -> PmLines {
+		PmLines {
+			remainder: *self,
+		}
+	}
+
+	/// Returns the number of `char`s in the string.
+	///
+	/// See [`PmString::char_count`].
+	pub fn char_count(&self) -> usize {
+		self.pm_utf8
+			.wrapper_iter()
+			.filter(|b| validations::is_utf8_char_boundary(b.load()))
+			.count()
+	}
+
+	/// Checks whether the byte at the given index is a char boundary.
+	///
+	/// See [`PmString::is_char_boundary`].
+	pub fn is_char_boundary(&self, idx: usize) -> bool {
+		match idx.cmp(&self.len()) {
+			Ordering::Less => {
+				idx == 0 || validations::is_utf8_char_boundary(self.pm_utf8.load_at(idx))
+			},
+			Ordering::Equal => true,
+			Ordering::Greater => false,
+		}
+	}
+
+	/// Returns the sub-string at the given byte range.
+	///
+	/// See [`PmString::get`].
+	pub fn get(&self, range: Range<usize>) -> Option<PmStr> {
+		if range.start <= range.end
+			&& self.is_char_boundary(range.start)
+			&& self.is_char_boundary(range.end)
+		{
+			// SAFETY: we just checked the char boundaries
+			Some(unsafe { self.get_unchecked(range) })
+		} else {
+			None
+		}
+	}
+
+	/// Divides the string into two at the given byte index.
+	///
+	/// See [`PmString::split_at`].
+	///
+	/// # Panics
+	///
+	/// This method panics, if `mid` is not on a char boundary or beyond the
+	/// end of the string.
+	pub fn split_at(&self, mid: usize) -> (PmStr, PmStr) {
+		assert!(
+			self.is_char_boundary(mid),
+			"The index is not on a char boundary"
+		);
+
+		// SAFETY: we just checked the char boundary
+		unsafe {
+			(
+				self.get_unchecked(0..mid),
+				self.get_unchecked(mid..self.len()),
+			)
+		}
+	}
+
+	/// Returns the sub-string at the given byte range, without checking for
+	/// char boundaries.
+	///
+	/// # Safety
+	///
+	/// The range must start and end on char boundaries.
+	///
+	/// # Panics
+	///
+	/// This function panics, if the range is out of bounds.
+	pub unsafe fn get_unchecked(&self, range: Range<usize>) -> PmStr {
+		// SAFETY: the caller ensures that the range is on char boundaries,
+		// thus the sub-slice is valid UTF-8 too
+		unsafe { PmStr::<[u8]>::new(self.pm_utf8.sub_slice(range)) }
+	}
+}
+
+impl fmt::Display for PmStr<[u8]> {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		for c in self.chars() {
+			write!(fmt, "{}", c)?
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for PmStr<[u8]> {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		for c in self.chars() {
+			ufmt::uwrite!(fmt, "{}", c)?
+		}
+		Ok(())
+	}
+}
+
+
+/// An iterator over the `char`s of a [`PmStr`] or [`PmString`]
+///
+/// Can be acquired via [`PmStr::chars`] or [`PmString::chars`].
+///
+/// # Safety
+///
+/// The inner byte iterator of this struct must yield valid UTF-8 sequence.
+#[non_exhaustive] // SAFETY: this struct must not be publicly constructible
+pub struct PmChars {
+	/// The inner byte iterator
+	///
+	/// # Safety
+	///
+	/// Must yield valid UTF-8 sequences.
+	bytes: PmBytes,
+}
+
+impl PmChars {
+	pub fn new(pm: &PmStr) -> Self {
+		// SAFETY: the contract on PmStr guarantees us that it wraps
+		// valid UTF-8, thus its byte iterator will yield valid UTF-8
+		PmChars {
+			bytes: PmBytes(pm.pm_utf8.wrapper_iter()),
+		}
+	}
+}
+
+impl Iterator for PmChars {
+	type Item = char;
+
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next(&mut self) -> Option<Self::Item> {
+		unsafe {
+			// SAFETY: the contract on `Self` struct guarantees us that we only
+			// get valid UTF-8 sequences
+			validations::next_code_point(&mut self.bytes)
+		}
+		.map(|u| core::char::from_u32(u).unwrap())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// Each char takes one to four bytes
+		let len = self.bytes.0.len();
+		(len / 4 + (len % 4 != 0) as usize, Some(len))
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl DoubleEndedIterator for PmChars {
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		unsafe {
+			// SAFETY: the contract on `Self` struct guarantees us that we only
+			// get valid UTF-8 sequences
+			validations::next_code_point_reverse(&mut self.bytes)
+		}
+		.map(|u| core::char::from_u32(u).unwrap())
+	}
+}
+
+impl FusedIterator for PmChars {}
+
+/// The bytes of a [`PmChars`], loaded one at a time.
+///
+/// This is just `PmWrapperIter` with each element loaded, but without a
+/// closure, so that the instrumentation can track the caller.
+struct PmBytes(PmWrapperIter<u8>);
+
+impl Iterator for PmBytes {
+	type Item = u8;
+
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next(&mut self) -> Option<Self::Item> {
+		let byte = self.0.next()?;
+		Some(byte.load())
+	}
+}
+
+impl DoubleEndedIterator for PmBytes {
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let byte = self.0.next_back()?;
+		Some(byte.load())
+	}
+}
+
+/// An iterator over the `char`s of a [`PmStr`] and their byte positions
+///
+/// Can be acquired via [`PmStr::char_indices`].
+pub struct PmCharIndices {
+	chars: PmChars,
+	/// The byte position of the next `char`
+	offset: usize,
+}
+
+impl Iterator for PmCharIndices {
+	type Item = (usize, char);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let c = self.chars.next()?;
+		let idx = self.offset;
+		self.offset += c.len_utf8();
+
+		Some((idx, c))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chars.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for PmCharIndices {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let c = self.chars.next_back()?;
+		// The remaining bytes are exactly those before `c`
+		let idx = self.offset + self.chars.bytes.0.len();
+
+		Some((idx, c))
+	}
+}
+
+impl FusedIterator for PmCharIndices {}
+
+/// An iterator over the sub-strings of a [`PmStr`] separated by a `char`
+///
+/// Can be acquired via [`PmStr::split`].
+pub struct PmSplit {
+	/// The rest of the string, `None` after the last sub-string
+	remainder: Option<PmStr>,
+	/// The separator encoded as UTF-8
+	sep_utf8: [u8; 4],
+	/// The length of the UTF-8 encoded separator
+	sep_len: usize,
+}
+
+impl Iterator for PmSplit {
+	type Item = PmStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.remainder?;
+
+		// SAFETY: the separator is a valid UTF-8 `str`
+		let sep = unsafe { core::str::from_utf8_unchecked(&self.sep_utf8[..self.sep_len]) };
+
+		match rest.find(sep) {
+			Some(idx) => {
+				// SAFETY: the separator lies on char boundaries, so do the
+				// sub-strings before and after it
+				let (sub, next) = unsafe {
+					(
+						rest.get_unchecked(0..idx),
+						rest.get_unchecked(idx + self.sep_len..rest.len()),
+					)
+				};
+				self.remainder = Some(next);
+				Some(sub)
+			},
+			None => {
+				self.remainder = None;
+				Some(rest)
+			},
+		}
+	}
+}
+
+/// An iterator over the lines of a [`PmStr`]
+///
+/// Can be acquired via [`PmStr::lines`].
+pub struct PmLines {
+	/// The rest of the string, empty after the last line
+	remainder: PmStr,
+}
+
+impl Iterator for PmLines {
+	type Item = PmStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.remainder;
+		if rest.is_empty() {
+			return None;
+		}
+
+		// SAFETY: the line feed is an ASCII char, thus the sub-strings before
+		// and after it, start and end on char boundaries
+		let (line, next) = match rest.pm_utf8.find_byte(b'\n') {
+			Some(idx) => {
+				// Also strip a carriage return before the line feed, just like
+				// `str::lines`, but keep a bare one
+				let end = if idx > 0 && rest.pm_utf8.load_at(idx - 1) == b'\r' {
+					idx - 1
+				} else {
+					idx
+				};
+				// SAFETY: the carriage return is an ASCII char too
+				unsafe {
+					(
+						rest.get_unchecked(0..end),
+						rest.get_unchecked(idx + 1..rest.len()),
+					)
+				}
+			},
+			None => unsafe { (rest, rest.get_unchecked(rest.len()..rest.len())) },
+		};
+		self.remainder = next;
+
+		Some(line)
+	}
+}
+
+
+
+/// A byte string anywhere in progmem
+///
+/// This is the far counterpart of [`PmString`], it wraps a byte array in a
+/// [`ProgMemFar`], which may be located anywhere in the program memory,
+/// e.g. beyond the 64 KiB limit on an ATmega2560.
+/// It is created by the `far string` rule of the [`progmem`](crate::progmem)
+/// macro.
+///
+/// Same as a `PmString`, it can be loaded into RAM as a [`LoadedString`],
+/// or lazily accessed by one char at a time via [`chars`](PmFarString::chars).
+///
+///
+/// # Safety
+///
+/// This type is a wrapper around [`ProgMemFar`], thus it any value of this
+/// type must be placed in program memory.
+/// See the [`ProgMemFar`] safety section for more details.
+///
+/// Additionally to the [`ProgMemFar`] contract, the byte array wrapped by
+/// this struct must be valid UTF-8.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::string::LoadedString;
+/// use avr_progmem::string::PmFarString;
+///
+/// progmem! {
+///     static progmem far string TEXT = "dai 大賢者 kenja";
+/// }
+///
+/// // The static has type `PmFarString`
+/// let text: &PmFarString<19> = &TEXT;
+/// // The loaded RAM string has type `LoadedString`
+/// let loaded: LoadedString<19> = text.load();
+/// // Which derefs to `&str`
+/// assert_eq!("dai 大賢者 kenja", &*loaded)
+/// ```
+///
+//
+//
+// SAFETY: this struct must not be publicly constructible
+#[non_exhaustive]
+//
+// Its just a pointer type, thus copy, clone & debug are fine (none of them
+// will access the progmem, that's what `Display` is for).
+#[derive(Copy, Clone, Debug)]
+// Also impl `uDebug` if enabled.
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PmFarString<const N: usize> {
+	/// The inner UTF-8 string as byte array anywhere in progmem.
+	///
+	/// # Safety
+	///
+	/// Must be valid UTF-8.
+	pm_utf8_array: ProgMemFar<[u8; N]>,
+}
+
+impl<const N: usize> PmFarString<N> {
+	/// Creates a new far byte array from the given string
+	///
+	/// You are encouraged to use the [`progmem`](crate::progmem) macro instead.
+	///
+	/// # Safety
+	///
+	/// This function is only sound to call, if the value is
+	/// is a valid `ProgMemFar`, and the underlying byte array contains valid
+	/// UTF-8.
+	pub const unsafe fn new(pm: ProgMemFar<[u8; N]>) -> Self {
+		// SAFETY: the caller ensures that the bytes are valid UTF-8
+		Self {
+			pm_utf8_array: pm,
+		}
+	}
+
+	/// Loads the entire string into RAM
+	///
+	/// If you have a very large string, consider using the lazy
+	/// [`chars`](Self::chars) iterator that accesses the string by one char at
+	/// a time and thus only requires a few bytes of stack memory.
+	///
+	pub fn load(&self) -> LoadedString<N> {
+		let array = self.load_bytes();
+
+		let bs_opt = unsafe {
+			// SAFETY: The contract on `Self` guarantees us that we have UTF-8
+			LoadedString::from_bytes(&array)
+		};
+
+		bs_opt.unwrap()
+	}
+
+	/// Loads the entire string as byte array into RAM
+	///
+	/// If you have a very large string, consider using the lazy
+	/// [`chars`](Self::chars) iterator or the respective byte iterator
+	/// (via `as_bytes().iter()`).
+	pub fn load_bytes(&self) -> [u8; N] {
+		self.as_bytes().load()
+	}
+
+	/// Returns the underlying far progmem byte array.
+	pub fn as_bytes(&self) -> &ProgMemFar<[u8; N]> {
+		&self.pm_utf8_array
+	}
+
+	/// Returns the length of the string in bytes.
+	pub fn len(&self) -> usize {
+		N
+	}
+
+	/// Returns whether the string is empty.
+	pub fn is_empty(&self) -> bool {
+		N == 0
+	}
+
+	/// Lazily iterate over the `char`s of the string.
+	///
+	/// This is the far variant of [`PmString::chars`].
+	pub fn chars(&self) -> PmFarChars {
+		// SAFETY: the contract on `Self` guarantees us that it wraps
+		// valid UTF-8, thus its byte iterator will yield valid UTF-8
+		PmFarChars {
+			bytes: self.pm_utf8_array.iter(),
+		}
+	}
+}
+
+impl<const N: usize> fmt::Display for PmFarString<N> {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		for c in self.chars() {
+			write!(fmt, "{}", c)?
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "ufmt")]
+impl<const N: usize> ufmt::uDisplay for PmFarString<N> {
+	fn fmt<W: ?Sized>(&self, fmt: &mut ufmt::Formatter<W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite,
+	{
+		for c in self.chars() {
+			ufmt::uwrite!(fmt, "{}", c)?
+		}
+		Ok(())
+	}
+}
+
+
+/// An iterator over a [`PmFarString`]
+///
+/// # Safety
+///
+/// The inner byte iterator of this struct must yield valid UTF-8 sequence.
+#[non_exhaustive] // SAFETY: this struct must not be publicly constructible
+pub struct PmFarChars {
+	/// The inner byte iterator
+	///
+	/// # Safety
+	///
+	/// Must yield valid UTF-8 sequences.
+	bytes: PmFarIter<u8>,
+}
+
+impl Iterator for PmFarChars {
+	type Item = char;
+
+	// We need `inline` here (and on the other methods), so the `elpm`
+	// instruction only gets assembled, when it is used.
+	// Otherwise, it would break the build on all the AVR devices without
+	// `elpm`.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		unsafe {
+			// SAFETY: the contract on `Self` struct guarantees us that we only
+			// get valid UTF-8 sequences
+			validations::next_code_point(&mut self.bytes)
+		}
+		.map(|u| core::char::from_u32(u).unwrap())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// Each char takes one to four bytes
+		let len = self.bytes.len();
+		(len / 4 + (len % 4 != 0) as usize, Some(len))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl DoubleEndedIterator for PmFarChars {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		unsafe {
+			// SAFETY: the contract on `Self` struct guarantees us that we only
+			// get valid UTF-8 sequences
+			validations::next_code_point_reverse(&mut self.bytes)
+		}
+		.map(|u| core::char::from_u32(u).unwrap())
+	}
+}
+
+impl FusedIterator for PmFarChars {}
+
+
+
+/// A table of strings of different lengths in progmem
+///
+/// Since the length of a [`PmString`] is part of its type, strings of
+/// different lengths can not be put into an array, and padding them all to
+/// the same length would waste a lot of flash.
+/// Instead, this type stores all the strings packed into a single byte
+/// array, plus an index of the offsets of each string, all in progmem.
+/// The strings of the table are accessed by their index as [`PmStr`].
+///
+/// You can create a `PmStringTable` via the `strings` rule of the
+/// [`progmem`](crate::progmem) macro.
+///
+///
+/// # Safety
+///
+/// This type is a wrapper around two [`ProgMem`], thus it any value of this
+/// type must be placed in program memory.
+/// See the [`ProgMem`] safety section for more details.
+///
+/// Additionally, the offsets must be ascending and within the bytes, and
+/// the bytes between each two offsets must be valid UTF-8.
+///
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::progmem;
+///
+/// progmem! {
+///     // A table of messages, each stored just with its own length
+///     static progmem strings MESSAGES = [
+///         "Ok",
+///         "Sensor failure",
+///         "大賢者",
+///     ];
+/// }
+///
+/// assert_eq!(3, MESSAGES.len());
+///
+/// // Print a single message, one `char` at a time
+/// let msg = MESSAGES.get(1).unwrap();
+/// assert_eq!("Sensor failure", format!("{}", msg));
+///
+/// // Or iterate over all of them
+/// let mut lengths = MESSAGES.iter().map(|s| s.len());
+/// assert_eq!(Some(2), lengths.next());
+/// assert_eq!(Some(14), lengths.next());
+/// assert_eq!(Some(9), lengths.next());
+/// assert_eq!(None, lengths.next());
+/// ```
+///
+//
+// SAFETY: this struct must not be publicly constructible
+#[non_exhaustive]
+//
+// Its just a pointer type, thus copy, clone & debug are fine.
+#[derive(Copy, Clone, Debug)]
+// Also impl `uDebug` if enabled.
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct PmStringTable {
+	/// All the strings concatenated.
+	///
+	/// # Safety
+	///
+	/// Must be valid UTF-8 between each two offsets.
+	blob: ProgMem<[u8]>,
+	/// The start offsets of all strings, followed by the length of `blob`.
+	///
+	/// # Safety
+	///
+	/// Must be ascending and within `blob`, and must not be empty.
+	offsets: ProgMem<[u16]>,
+}
+
+impl PmStringTable {
+	/// Creates a new string table
+	///
+	/// You are encouraged to use the `strings` rule of the
+	/// [`progmem`](crate::progmem) macro instead.
+	///
+	/// # Safety
+	///
+	/// This function is only sound to call, if both `blob` and `offsets` are
+	/// valid `ProgMem`s, the `offsets` are ascending, start at zero, end with
+	/// the length of the `blob`, and the bytes between each two offsets are
+	/// valid UTF-8.
+	pub const unsafe fn new(blob: ProgMem<[u8]>, offsets: ProgMem<[u16]>) -> Self {
+		// SAFETY: the caller ensures the invariants
+		Self {
+			blob,
+			offsets,
+		}
+	}
+
+	/// Returns the number of strings in the table.
+	pub fn len(&self) -> usize {
+		// There is one more offset than strings
+		self.offsets.len() - 1
+	}
+
+	/// Returns whether the table contains no strings.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the string at the given index, or `None` if it is out of
+	/// bounds.
+	///
+	/// This loads just the two offsets of that string from progmem.
+	pub fn get(&self, idx: usize) -> Option<PmStr> {
+		if idx >= self.len() {
+			return None;
+		}
+
+		let start = self.offsets.load_at(idx) as usize;
+		let end = self.offsets.load_at(idx + 1) as usize;
+
+		// SAFETY: the contract on `Self` guarantees us that the bytes
+		// between two offsets are valid UTF-8
+		Some(unsafe { PmStr::<[u8]>::new(self.blob.sub_slice(start..end)) })
+	}
+
+	/// Iterate over all strings of the table.
+	pub fn iter(&self) -> PmStringTableIter {
+		PmStringTableIter {
+			table: *self,
+			current_idx: 0,
+		}
+	}
+}
+
+/// Same as [`PmStringTable::iter`]
+impl IntoIterator for &PmStringTable {
+	type IntoIter = PmStringTableIter;
+	type Item = PmStr;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An iterator over the strings of a [`PmStringTable`]
+///
+/// Can be acquired via [`PmStringTable::iter`].
+pub struct PmStringTableIter {
+	table: PmStringTable,
+	current_idx: usize,
+}
+
+impl Iterator for PmStringTableIter {
+	type Item = PmStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let s = self.table.get(self.current_idx)?;
+		self.current_idx += 1;
+
+		Some(s)
 	}
 }
 
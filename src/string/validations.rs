@@ -24,6 +24,16 @@ const fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 {
 	(ch << 6) | (byte & CONT_MASK) as u32
 }
 
+/// Checks whether the byte starts a char, i.e. it is not a continuation byte.
+///
+/// This is analog to the (unstable) `u8::is_utf8_char_boundary`.
+#[inline]
+pub(super) const fn is_utf8_char_boundary(byte: u8) -> bool {
+	// This is bit magic equivalent to: byte < 128 || byte >= 192
+	(byte as i8) >= -0x40
+}
+
+
 
 /// Reads the next code point out of a byte iterator (assuming a
 /// UTF-8-like encoding).
@@ -32,6 +42,7 @@ const fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 {
 ///
 /// `bytes` must produce a valid UTF-8-like (UTF-8 or WTF-8) string
 #[inline]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
 pub(super) unsafe fn next_code_point<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<u32> {
 	// Decode UTF-8
 	let x = bytes.next()?;
@@ -67,3 +78,121 @@ pub(super) unsafe fn next_code_point<I: Iterator<Item = u8>>(bytes: &mut I) -> O
 
 	Some(ch)
 }
+
+/// Reads the last code point out of a byte iterator (assuming a
+/// UTF-8-like encoding).
+///
+/// # Safety
+///
+/// `bytes` must produce a valid UTF-8-like (UTF-8 or WTF-8) string
+#[inline]
+#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+pub(super) unsafe fn next_code_point_reverse<I: DoubleEndedIterator<Item = u8>>(
+	bytes: &mut I,
+) -> Option<u32> {
+	// Decode UTF-8
+	let w = match bytes.next_back()? {
+		next_byte if next_byte < 128 => return Some(next_byte as u32),
+		back_byte => back_byte,
+	};
+
+	// Multibyte case follows
+	// Decode from a byte combination out of: [x [y [z w]]]
+	let mut ch;
+	// SAFETY: `bytes` produces an UTF-8-like string,
+	// so the iterator must produce a value here.
+	let z = bytes.next_back().unwrap();
+	ch = utf8_first_byte(z, 2);
+	if !is_utf8_char_boundary(z) {
+		// SAFETY: `bytes` produces an UTF-8-like string,
+		// so the iterator must produce a value here.
+		let y = bytes.next_back().unwrap();
+		ch = utf8_first_byte(y, 3);
+		if !is_utf8_char_boundary(y) {
+			// SAFETY: `bytes` produces an UTF-8-like string,
+			// so the iterator must produce a value here.
+			let x = bytes.next_back().unwrap();
+			ch = utf8_first_byte(x, 4);
+			ch = utf8_acc_cont_byte(ch, y);
+		}
+		ch = utf8_acc_cont_byte(ch, z);
+	}
+	ch = utf8_acc_cont_byte(ch, w);
+
+	Some(ch)
+}
+
+
+/// Returns the length of the UTF-8 sequence starting with the given byte, or
+/// zero if the byte can not start a sequence.
+#[inline]
+const fn utf8_char_width(first: u8) -> usize {
+	match first {
+		0x00..=0x7F => 1,
+		0xC2..=0xDF => 2,
+		0xE0..=0xEF => 3,
+		0xF0..=0xF4 => 4,
+		_ => 0,
+	}
+}
+
+/// Checks whether the `byte` at position `idx` (starting at one) of a UTF-8
+/// sequence is valid after the given first byte.
+///
+/// The second byte is restricted further to exclude overlong encodings,
+/// surrogates, and code points beyond U+10FFFF.
+#[inline]
+const fn is_valid_cont_byte(first: u8, idx: usize, byte: u8) -> bool {
+	if idx > 1 {
+		return byte & !CONT_MASK == 0x80;
+	}
+
+	matches!(
+		(first, byte),
+		(0xC2..=0xDF, 0x80..=0xBF)
+			| (0xE0, 0xA0..=0xBF)
+			| (0xE1..=0xEC, 0x80..=0xBF)
+			| (0xED, 0x80..=0x9F)
+			| (0xEE..=0xEF, 0x80..=0xBF)
+			| (0xF0, 0x90..=0xBF)
+			| (0xF1..=0xF3, 0x80..=0xBF)
+			| (0xF4, 0x80..=0x8F)
+	)
+}
+
+/// Reads the next code point out of a byte iterator, checking that it is
+/// valid UTF-8.
+///
+/// This is the checked counterpart of [`next_code_point`], it returns the
+/// code point along with the length of its sequence, or in case of an invalid
+/// sequence the `error_len` of a [`Utf8Error`](super::Utf8Error), i.e. `None`
+/// if the bytes end in the middle of the sequence.
+///
+/// Each byte is only read once, thus the iterator is left right after the
+/// sequence, or after the first byte that does not fit into it.
+pub(super) fn try_next_code_point<I: Iterator<Item = u8>>(
+	bytes: &mut I,
+) -> Result<Option<(u32, usize)>, Option<u8>> {
+	let first = match bytes.next() {
+		Some(first) => first,
+		None => return Ok(None),
+	};
+
+	let width = utf8_char_width(first);
+	match width {
+		0 => return Err(Some(1)),
+		1 => return Ok(Some((first as u32, 1))),
+		_ => {},
+	}
+
+	let mut ch = utf8_first_byte(first, width as u32);
+	for idx in 1..width {
+		let byte = bytes.next().ok_or(None)?;
+		if !is_valid_cont_byte(first, idx, byte) {
+			return Err(Some(idx as u8));
+		}
+		ch = utf8_acc_cont_byte(ch, byte);
+	}
+
+	Ok(Some((ch, width)))
+}
@@ -19,11 +19,42 @@
 //! [`ProgMem`] struct for you.
 
 
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::align_of;
+use core::mem::size_of;
+use core::mem::size_of_val;
+use core::mem::MaybeUninit;
+use core::ops::Deref;
+use core::ops::Range;
+
 use derivative::Derivative;
 
+#[cfg(doc)]
+use crate::pm_field;
 #[cfg(doc)]
 use crate::progmem;
+use crate::raw;
+use crate::raw::compare_bytes;
+use crate::raw::compare_bytes_far;
+use crate::raw::read_slice;
+use crate::raw::read_slice_far;
 use crate::raw::read_value;
+use crate::raw::read_value_far;
+use crate::raw::BytewiseEq;
+use crate::raw::FarAddr;
+
+
+
+/// The size in bytes of the stack buffer used by [`ProgMem::position`].
+///
+/// As many elements as fit into this buffer are loaded at once.
+pub const SEARCH_BUFFER_SIZE: usize = 32;
+
+/// The stack buffer of [`ProgMem::position`], aligned for any primitive.
+#[repr(C, align(16))]
+struct SearchBuffer([MaybeUninit<u8>; SEARCH_BUFFER_SIZE]);
 
 
 
@@ -60,6 +91,12 @@ use crate::raw::read_value;
 /// [`as_slice`][ProgMem::as_slice] method, or by enabling the "unsize" crate
 /// feature that allows normal Rust coercing.
 ///
+/// With the "elpm" crate feature, the wrappers created by the [`progmem`]
+/// macro additionally know the full far address of their `static`, and load
+/// it via the `elpm` instruction, thus they work anywhere in the program
+/// memory, even beyond the 64 KiB limit of normal pointers (see
+/// `ProgMem::new_far`).
+///
 ///
 /// # Safety
 ///
@@ -97,6 +134,14 @@ pub struct ProgMem<T: ?Sized> {
 	///
 	/// See the struct doc.
 	target: *const T,
+	/// The far address of the `static` that `target` points into, if this
+	/// wrapper loads via `elpm`.
+	///
+	/// # Safety
+	///
+	/// See `ProgMem::new_far`.
+	#[cfg(feature = "elpm")]
+	far: Option<FarOrigin>,
 }
 
 
@@ -141,9 +186,96 @@ impl<T: ?Sized> ProgMem<T> {
 	/// Instead, if you want to use the pointer, you may want to use one of
 	/// the "raw" functions, see the [raw](crate::raw) module.
 	///
+	/// With the "strict-host" crate feature, this pointer is also not
+	/// dereferenceable on 64-bit non-AVR targets, so such bugs crash in
+	/// tests, too (see `STRICT_HOST_TAG` in the [raw](crate::raw) module).
+	///
+	/// With the "elpm" crate feature, the value might be located beyond the
+	/// 64 KiB limit, where this pointer is truncated, and thus it is not
+	/// suitable for the near "raw" functions.
+	///
 	pub fn as_ptr(&self) -> *const T {
 		self.target
 	}
+
+	/// Returns a wrapper of `target`, which points into the inner value.
+	///
+	/// The new wrapper keeps the far address of the inner value (if any),
+	/// resolved, so it is not looked up again.
+	///
+	/// # Safety
+	///
+	/// `target` must point to a valid `U` within the inner value.
+	unsafe fn derive<U: ?Sized>(&self, target: *const U) -> ProgMem<U> {
+		ProgMem {
+			target,
+			#[cfg(feature = "elpm")]
+			far: self.far.map(FarOrigin::resolved),
+		}
+	}
+
+	/// Returns this wrapper with its far address (if any) resolved.
+	///
+	/// This is used before loading several elements, so the far address is
+	/// only looked up once.
+	fn resolved(&self) -> Self {
+		// SAFETY: the target is the inner value itself
+		unsafe { self.derive(self.target) }
+	}
+
+	/// Returns the far address of `ptr`, which points into the inner value,
+	/// if this wrapper loads via `elpm`.
+	#[cfg(feature = "elpm")]
+	fn far_addr<U: ?Sized>(&self, ptr: *const U) -> Option<FarAddr> {
+		self.far.map(|far| far.addr_of(ptr.cast()))
+	}
+
+	/// Returns the far address of `ptr`, which is always `None` without the
+	/// "elpm" crate feature.
+	#[cfg(not(feature = "elpm"))]
+	fn far_addr<U: ?Sized>(&self, _ptr: *const U) -> Option<FarAddr> {
+		None
+	}
+
+	/// Reads the `U` at `ptr`, which points into the inner value, either via
+	/// [`read_value`] or [`read_value_far`].
+	///
+	/// # Safety
+	///
+	/// `ptr` must point to a valid `U` within the inner value.
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	unsafe fn read_value_at<U: Copy>(&self, ptr: *const U) -> U {
+		match self.far_addr(ptr) {
+			Some(addr) => unsafe { read_value_far(addr) },
+			None => unsafe { read_value(ptr) },
+		}
+	}
+
+	/// Reads the `U`s starting at `ptr`, which points into the inner value,
+	/// into `out`, either via [`read_slice`] or [`read_slice_far`].
+	///
+	/// # Safety
+	///
+	/// `ptr` must point to `out.len()` many valid `U`s within the inner value.
+	unsafe fn read_slice_at<U: Copy>(&self, ptr: *const U, out: &mut [U]) {
+		match self.far_addr(ptr) {
+			Some(addr) => unsafe { read_slice_far(addr, out) },
+			None => unsafe { read_slice(ptr, out) },
+		}
+	}
+
+	/// Compares the bytes starting at `ptr`, which points into the inner
+	/// value, either via [`compare_bytes`] or [`compare_bytes_far`].
+	///
+	/// # Safety
+	///
+	/// `ptr` must point to `data.len()` many bytes within the inner value.
+	unsafe fn compare_bytes_at(&self, ptr: *const u8, data: &[u8]) -> Ordering {
+		match self.far_addr(ptr) {
+			Some(addr) => unsafe { compare_bytes_far(addr, data) },
+			None => unsafe { compare_bytes(ptr, data) },
+		}
+	}
 }
 
 impl<T> ProgMem<T> {
@@ -177,36 +309,150 @@ impl<T> ProgMem<T> {
 	/// architectures.
 	///
 	pub const unsafe fn new(target: *const T) -> Self {
+		ProgMem {
+			target: crate::raw::strict_host_tag(target),
+			#[cfg(feature = "elpm")]
+			far: None,
+		}
+	}
+
+	/// Construct a new instance of this type, which loads via `elpm`.
+	///
+	/// This is only available with the "elpm" crate feature, where the
+	/// [`progmem`] macro uses it for you.
+	/// Unlike a wrapper created by [`new`](Self::new), this wrapper (and all
+	/// the wrappers derived from it, e.g. via [`at`](ProgMem::at)) loads the
+	/// value from its full far address, as returned by `base`, thus the value
+	/// may be located anywhere in the program memory.
+	/// Notice, that the `elpm` instruction is only available on AVR devices
+	/// with more than 64 KiB of flash.
+	///
+	///
+	/// # Safety
+	///
+	/// Same as for [`new`](Self::new), additionally, the given `base`
+	/// function must always return the far address of the value to which
+	/// `target` points, e.g. via the [`far_addr_of`](crate::far_addr_of)
+	/// macro.
+	/// However, `target` itself may be truncated to 16 bits, as it is only
+	/// used to locate fields and elements relative to the value.
+	///
+	#[cfg(feature = "elpm")]
+	pub const unsafe fn new_far(target: *const T, base: fn() -> FarAddr) -> Self {
+		let target = crate::raw::strict_host_tag(target);
+
 		ProgMem {
 			target,
+			far: Some(FarOrigin {
+				base: FarBase::Static(base),
+				origin: target.cast(),
+			}),
 		}
 	}
+
+	/// Get a reference to the inner value, without loading it.
+	///
+	/// This is only available with the "mapped-flash" crate feature,
+	/// i.e. on AVR cores, which map the program memory into the data address
+	/// space (see [`MAPPED_FLASH_OFFSET`](crate::raw::MAPPED_FLASH_OFFSET)).
+	/// There, the value can be accessed directly via the memory-mapped
+	/// window, without copying it into RAM first.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u8; 3] = [1,2,3];
+	/// }
+	///
+	/// #[cfg(feature = "mapped-flash")]
+	/// assert_eq!(&[1,2,3], ARR.as_ref());
+	/// ```
+	///
+	#[cfg(feature = "mapped-flash")]
+	pub fn as_ref(&self) -> &'static T {
+		// SAFETY: the invariant of this struct guarantees that `target` points
+		// to a valid, immutable, `'static` value in the progmem, which is
+		// memory-mapped in the data domain, thus we may reference it there.
+		unsafe { &*crate::raw::mapped_ptr(self.target) }
+	}
+
+	/// Get a wrapper of a part of the inner value, without loading it.
+	///
+	/// The given `project` function is called with the pointer to the inner
+	/// value, and must return a pointer to one of its fields, e.g. via
+	/// `addr_of!((*ptr).field)`.
+	/// Usually, you want to use the safe [`pm_field`] macro instead, which
+	/// calls this method for you.
+	///
+	/// # Safety
+	///
+	/// The `project` function must not dereference the given pointer (it
+	/// points into the progmem), it may only compute the address of a field
+	/// of the `T` from it.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the returned pointer does not point into the
+	/// inner value.
+	///
+	pub unsafe fn project<U>(&self, project: impl FnOnce(*const T) -> *const U) -> ProgMem<U> {
+		// Remove any strict host tag, so the pointer can be projected
+		let ptr = crate::raw::strict_host_untag(self.target);
+
+		let field_ptr = project(ptr);
+
+		// SAFETY: the field must lie within the inner value, which makes it
+		// also a value in progmem.
+		let start = ptr as usize;
+		let field = field_ptr as usize;
+		assert!(
+			start <= field && field + size_of::<U>() <= start + size_of::<T>(),
+			"The projected field is not within the value"
+		);
+
+		// SAFETY: we checked above that the field lies within the inner
+		// value, which is in the progmem domain, as guaranteed by the
+		// invariant of this struct.
+		unsafe { self.derive(crate::raw::strict_host_tag(field_ptr)) }
+	}
 }
 
 impl<T: Copy> ProgMem<T> {
 	/// Read the inner value from progmem and return a regular value.
 	///
-	/// # Panics
+	/// Notice, that the entire value is loaded onto the stack, so if you have
+	/// a really big value, e.g. with hundreds of bytes, you might be close to a
+	/// stack overflow, at least on an Arduino Uno.
+	/// Thus it might be better to restructure your data, so you can store it
+	/// as an array of something, than you can use the [`load_at`] and
+	/// [`load_sub_array`] methods instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
 	///
-	/// This method panics, if the size of the value (i.e. `size_of::<T>()`)
-	/// is beyond 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
+	/// progmem!{
+	///	    static progmem DATA: [u8; 300] = [42; 300];
+	/// }
 	///
-	/// Also notice, if you really hit this limit, you would need 256+ bytes on
-	/// your stack, on the Arduino Uno (at least) that means that you might be
-	/// close to a stack overflow. Thus it might be better to restructure your
-	/// data, so you can store it as an array of something, than you can use
-	/// the [`load_at`] and [`load_sub_array`] methods instead.
+	/// // Values beyond 255 bytes can be loaded at once
+	/// let data: [u8; 300] = DATA.load();
+	/// assert_eq!([42; 300], data);
+	/// ```
 	///
 	/// [`load_at`]: struct.ProgMem.html#method.load_at
 	/// [`load_sub_array`]: struct.ProgMem.html#method.load_sub_array
 	///
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
 	pub fn load(&self) -> T {
 		// This is safe, because the invariant of this struct guarantees that
 		// this value (i.e. target) is stored in the progmem domain,
 		// which is what `read_value` requires from us.
-		unsafe { read_value(self.target) }
+		unsafe { self.read_value_at(self.target) }
 	}
 }
 
@@ -267,9 +513,11 @@ impl<T, const N: usize> ProgMem<[T; N]> {
 	/// let s: ProgMem<[u8]> = arr;
 	/// ```
 	///
-	pub fn as_slice(&self) -> ProgMem<[T]> {
+	pub const fn as_slice(&self) -> ProgMem<[T]> {
 		ProgMem {
 			target: self.target,
+			#[cfg(feature = "elpm")]
+			far: self.far,
 		}
 	}
 }
@@ -288,14 +536,10 @@ impl<T: Copy, const N: usize> ProgMem<[T; N]> {
 	/// This method panics, if the given index `idx` is grater or equal to the
 	/// length `N` of the inner type.
 	///
-	/// This method also panics, if the size of the value (i.e. `size_of::<T>()`)
-	/// is beyond 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
-	///
 	/// Notice, that here `T` is the type of the elements not the entire array
 	/// as it would be with [`load`](Self::load).
 	///
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
 	pub fn load_at(&self, idx: usize) -> T {
 		// Just get the element wrapper and load it
 		self.at(idx).load()
@@ -319,11 +563,6 @@ impl<T: Copy, const N: usize> ProgMem<[T; N]> {
 	/// length `N` of the inner array, or the end index `idx+M` is grater than
 	/// the length `N` of the inner array.
 	///
-	/// This method also panics, if the size of the value
-	/// (i.e. `size_of::<[T;M]>()`) is beyond 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
-	///
 	pub fn load_sub_array<const M: usize>(&self, start_idx: usize) -> [T; M] {
 		// Just a check to give a nicer panic message
 		assert!(
@@ -349,7 +588,149 @@ impl<T: Copy, const N: usize> ProgMem<[T; N]> {
 		// SAFETY: This is safe, because the invariant of this struct demands
 		// that this value (i.e. self and thus also its inner value) are stored
 		// in the progmem domain, which is what `read_value` requires from us.
-		unsafe { read_value(sub_array_ptr) }
+		unsafe { self.read_value_at(sub_array_ptr) }
+	}
+
+	/// Loads a range of elements from the inner array into the given buffer.
+	///
+	/// This method is analog to `buffer.copy_from_slice(&self.load()[range])`,
+	/// but it copies the elements directly from progmem into the given
+	/// `buffer`, without loading the entire array onto the stack first.
+	/// Thus, it is well suited to fill existing buffers in RAM.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u8; 6] = [1,2,3,4,5,6];
+	/// }
+	///
+	/// let mut buffer = [0_u8; 4];
+	/// ARR.load_range_into(2..5, &mut buffer[..3]);
+	/// assert_eq!([3,4,5,0], buffer);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given `range` is not within the bounds of
+	/// the inner array, or if the length of the `range` differs from the
+	/// length of the `buffer`.
+	///
+	pub fn load_range_into(&self, range: Range<usize>, buffer: &mut [T]) {
+		// SAFETY: bounds check, the range must be within the source array
+		assert!(
+			range.start <= range.end && range.end <= N,
+			"The range goes beyond the end of the source array"
+		);
+
+		let first_source_element_ptr: *const T = self.target.cast();
+
+		// Get a pointer to the first selected element
+		let first_range_element_ptr = first_source_element_ptr.wrapping_add(range.start);
+
+		// Pointer to a sub slice of the source
+		let sub_slice_ptr: *const [T] =
+			core::ptr::slice_from_raw_parts(first_range_element_ptr, range.len());
+
+		// This sound, because `self.target` is in program domain and we checked
+		// above that the range is in bound, thus that sub slice is also
+		// valid and pointing into the program domain.
+		let sub_slice = unsafe { self.derive(sub_slice_ptr) };
+
+		sub_slice.load_into(buffer)
+	}
+
+	/// Returns the index of the first element satisfying the predicate.
+	///
+	/// This method is analog to `self.iter().position(predicate)`, but it
+	/// loads several elements at once, see [`ProgMem::position`] of the
+	/// slice wrapper.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u16; 5] = [10,20,30,40,50];
+	/// }
+	///
+	/// assert_eq!(Some(3), ARR.position(|x| x > 35));
+	/// assert_eq!(None, ARR.position(|x| x > 50));
+	/// ```
+	///
+	pub fn position<P>(&self, predicate: P) -> Option<usize>
+	where
+		P: FnMut(T) -> bool,
+	{
+		self.as_slice().position(predicate)
+	}
+
+	/// Returns whether the inner array contains an element with the given
+	/// value.
+	///
+	/// See [`position`](Self::position).
+	///
+	pub fn contains(&self, x: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		self.as_slice().contains(x)
+	}
+
+	/// Binary searches this sorted array with a comparator function.
+	///
+	/// This method is analog to [`slice::binary_search_by`], but it loads
+	/// only the probed elements, see [`ProgMem::binary_search_by`] of the
+	/// slice wrapper.
+	///
+	pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(T) -> Ordering,
+	{
+		self.as_slice().binary_search_by(f)
+	}
+
+	/// Binary searches this sorted array for a given element.
+	///
+	/// This method is analog to [`slice::binary_search`], but it loads only
+	/// the probed elements.
+	/// Use the `sorted` rule of the [`progmem`] macro to ensure at
+	/// compile-time that the array is actually sorted.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///     static progmem sorted KEY_CODES: [u8; 6] = [4, 8, 15, 16, 23, 42];
+	/// }
+	///
+	/// assert_eq!(Ok(3), KEY_CODES.binary_search(&16));
+	/// assert_eq!(Err(5), KEY_CODES.binary_search(&30));
+	/// ```
+	///
+	pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+	where
+		T: Ord,
+	{
+		self.as_slice().binary_search(x)
+	}
+
+	/// Binary searches this sorted array with a key extraction function.
+	///
+	/// This method is analog to [`slice::binary_search_by_key`], but it loads
+	/// only the probed elements.
+	///
+	pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(T) -> B,
+		B: Ord,
+	{
+		self.as_slice().binary_search_by_key(b, f)
 	}
 
 	/// Lazily iterate over all elements
@@ -359,13 +740,6 @@ impl<T: Copy, const N: usize> ProgMem<[T; N]> {
 	/// This means this iterator can be used to access huge arrays while
 	/// only requiring `size_of::<T>()` amount of stack memory.
 	///
-	/// # Panics
-	///
-	/// The returned iterator will panic, if the size of an element (i.e. `size_of::<T>()`)
-	/// is beyond 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
-	///
 	/// Notice, that here `T` is the type of the elements not the entire array
 	/// as it would be with [`load`](Self::load).
 	///
@@ -374,6 +748,128 @@ impl<T: Copy, const N: usize> ProgMem<[T; N]> {
 	}
 }
 
+/// Utilities to compare an array wrapper against data in RAM.
+///
+/// These compare the array byte-wise directly in progmem (analog to
+/// `memcmp_P` of the avr-libc), stopping at the first mismatch, without
+/// loading the array onto the stack first.
+impl<T: BytewiseEq, const N: usize> ProgMem<[T; N]> {
+	/// Returns whether the inner array is equal to the given slice.
+	///
+	/// This method is analog to `self.load() == data`, but it compares the
+	/// elements directly in progmem, thus it is well suited for huge arrays.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u16; 4] = [1,2,3,4];
+	/// }
+	///
+	/// assert!(ARR.eq_slice(&[1,2,3,4]));
+	/// assert!(!ARR.eq_slice(&[1,2,3,5]));
+	/// assert!(!ARR.eq_slice(&[1,2,3]));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner array is 64 KiB or larger.
+	///
+	pub fn eq_slice(&self, data: &[T]) -> bool {
+		if data.len() != N {
+			return false;
+		}
+
+		// SAFETY: `BytewiseEq` types have no padding, thus all their bytes
+		// are initialized and the slice may be viewed as bytes.
+		let bytes =
+			unsafe { core::slice::from_raw_parts(data.as_ptr().cast::<u8>(), size_of_val(data)) };
+
+		// SAFETY: `self.target` points to an `[T; N]` in progmem, which has
+		// the same size as `data`, as checked above.
+		let ord = unsafe { self.compare_bytes_at(self.target.cast(), bytes) };
+
+		ord == Ordering::Equal
+	}
+}
+
+/// Utilities to compare a byte array wrapper against bytes in RAM.
+impl<const N: usize> ProgMem<[u8; N]> {
+	/// Compares the inner byte array lexicographically with the given bytes.
+	///
+	/// This method is analog to `self.load()[..].cmp(data)`, but it compares
+	/// the bytes directly in progmem, stopping at the first mismatch.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	/// use core::cmp::Ordering;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u8; 5] = *b"Hello";
+	/// }
+	///
+	/// assert_eq!(Ordering::Equal, ARR.cmp_slice(b"Hello"));
+	/// assert_eq!(Ordering::Less, ARR.cmp_slice(b"World"));
+	/// assert_eq!(Ordering::Less, ARR.cmp_slice(b"Hello World"));
+	/// assert_eq!(Ordering::Greater, ARR.cmp_slice(b"Hell"));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner array is 64 KiB or larger.
+	///
+	pub fn cmp_slice(&self, data: &[u8]) -> Ordering {
+		self.as_slice().cmp_slice(data)
+	}
+}
+
+/// Utilities to search in a byte array wrapper.
+impl<const N: usize> ProgMem<[u8; N]> {
+	/// Returns the index of the first occurrence of the given byte.
+	///
+	/// This method is analog to `memchr_P` of the avr-libc, it scans the
+	/// bytes directly in progmem via [`raw::find_byte`], thus it is well
+	/// suited to find delimiters in huge texts.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem TEXT: [u8; 11] = *b"key = value";
+	/// }
+	///
+	/// assert_eq!(Some(4), TEXT.find_byte(b'='));
+	/// assert_eq!(None, TEXT.find_byte(b';'));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner array is 64 KiB or larger.
+	///
+	pub fn find_byte(&self, needle: u8) -> Option<usize> {
+		self.as_slice().find_byte(needle)
+	}
+
+	/// Returns the index of the last occurrence of the given byte.
+	///
+	/// This method is analog to [`find_byte`](Self::find_byte), but scans
+	/// the bytes backwards via [`raw::rfind_byte`].
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner array is 64 KiB or larger.
+	///
+	pub fn rfind_byte(&self, needle: u8) -> Option<usize> {
+		self.as_slice().rfind_byte(needle)
+	}
+}
+
 /// Utilities to work with an slice wrapper.
 ///
 /// You can obtain a slice wrapper by coercing an array wrapper.
@@ -396,104 +892,1281 @@ impl<T> ProgMem<[T]> {
 		// This sound, because `self.target` is in program domain and we checked
 		// above that `idx` is in bound, thus that element pointer is also
 		// valid and pointing into the program domain.
-		ProgMem {
-			target: element_ptr,
-		}
+		unsafe { self.derive(element_ptr) }
 	}
 
-	/// Iterate over all elements as wrappers.
+	/// Get a reference to a sub-slice, without loading it.
 	///
-	/// Returns an iterator, which yields each element as a `ProgMem<T>`,
-	/// which can be subsequently loaded.
-	pub fn wrapper_iter(&self) -> PmWrapperIter<T> {
-		PmWrapperIter::new(*self)
-	}
-
-	/// Returns the length of the slice
-	pub fn len(&self) -> usize {
+	/// This method is analog to a slice indexing with a range (i.e.
+	/// `&slice[range]`), except that it yields a `ProgMem` of that sub-slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u8; 5] = [1,2,3,4,5];
+	/// }
+	///
+	/// let s: ProgMem<[u8]> = ARR.as_slice().sub_slice(1..4);
+	/// assert_eq!(3, s.len());
+	/// assert_eq!(2, s.load_at(0));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given `range` is not within the bounds of
+	/// the slice.
+	pub fn sub_slice(&self, range: Range<usize>) -> ProgMem<[T]> {
+		// SAFETY: bounds check, the range must be within the slice
+		assert!(
+			range.start <= range.end && range.end <= self.target.len(),
+			"The range goes beyond the end of the slice"
+		);
+
+		let first_element_ptr: *const T = self.target.cast();
+
+		// Get a pointer to the first selected element
+		let first_range_element_ptr = first_element_ptr.wrapping_add(range.start);
+
+		// This sound, because `self.target` is in program domain and we checked
+		// above that the range is in bound, thus that sub slice is also
+		// valid and pointing into the program domain.
+		unsafe {
+			self.derive(core::ptr::slice_from_raw_parts(
+				first_range_element_ptr,
+				range.len(),
+			))
+		}
+	}
+
+	/// Iterate over all elements as wrappers.
+	///
+	/// Returns an iterator, which yields each element as a `ProgMem<T>`,
+	/// which can be subsequently loaded.
+	pub fn wrapper_iter(&self) -> PmWrapperIter<T> {
+		PmWrapperIter::new(*self)
+	}
+
+	/// Returns the length of the slice
+	pub fn len(&self) -> usize {
 		self.target.len()
 	}
+
+	/// Get a reference to the slice, without loading it.
+	///
+	/// This is only available with the "mapped-flash" crate feature,
+	/// see [`ProgMem::as_ref`].
+	#[cfg(feature = "mapped-flash")]
+	pub fn as_ref(&self) -> &'static [T] {
+		let first_element_ptr = crate::raw::mapped_ptr(self.target.cast::<T>());
+
+		// SAFETY: the invariant of this struct guarantees that `target` points
+		// to a valid, immutable, `'static` slice in the progmem, which is
+		// memory-mapped in the data domain, thus we may reference it there.
+		unsafe { core::slice::from_raw_parts(first_element_ptr, self.target.len()) }
+	}
+}
+
+/// Loading elements of an array in progmem.
+impl<T: Copy> ProgMem<[T]> {
+	/// Load a single element from the slice.
+	///
+	/// This method is analog to a slice indexing, thus the same requirements
+	/// apply: the index `idx` should be less then the length of the slice,
+	/// otherwise a panic will be risen.
+	///
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length of the slice.
+	///
+	/// Notice, that here `T` is the type of the elements not the entire slice.
+	///
+	pub fn load_at(&self, idx: usize) -> T {
+		// Just get the element wrapper and load it
+		self.at(idx).load()
+	}
+
+	/// Loads the entire slice into the given buffer.
+	///
+	/// This method is analog to `buffer.copy_from_slice(slice)`, but it copies
+	/// the elements directly from progmem into the given `buffer`, without
+	/// requiring any additional stack memory.
+	/// Thus, it is well suited to fill existing buffers in RAM.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u8; 3] = [1,2,3];
+	/// }
+	///
+	/// let s: ProgMem<[u8]> = ARR.as_slice();
+	///
+	/// let mut buffer = [0_u8; 3];
+	/// s.load_into(&mut buffer);
+	/// assert_eq!([1,2,3], buffer);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the length of the `buffer` differs from the
+	/// length of the slice.
+	///
+	pub fn load_into(&self, buffer: &mut [T]) {
+		// SAFETY: bounds check, we must fill the buffer exactly
+		assert_eq!(
+			self.target.len(),
+			buffer.len(),
+			"The buffer length must be equal to the slice length"
+		);
+
+		// SAFETY: This is safe, because the invariant of this struct demands
+		// that this value (i.e. self and thus also its inner value) are stored
+		// in the progmem domain, which is what `read_slice` requires from us.
+		// Also we checked above, that `buffer` has exactly the same length.
+		unsafe { self.read_slice_at(self.target.cast(), buffer) }
+	}
+
+	/// Returns the index of the first element satisfying the predicate.
+	///
+	/// This method is analog to `Iterator::position`, but instead of
+	/// loading the elements one at a time, it loads as many elements as fit
+	/// into a small buffer of [`SEARCH_BUFFER_SIZE`] bytes on the stack at
+	/// once, which reduces the overhead per element.
+	/// Elements larger than that buffer are loaded one at a time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u16; 5] = [10,20,30,40,50];
+	/// }
+	///
+	/// let s: ProgMem<[u16]> = ARR.as_slice();
+	///
+	/// assert_eq!(Some(1), s.position(|x| x % 20 == 0));
+	/// assert!(s.contains(&50));
+	/// assert!(!s.contains(&60));
+	/// ```
+	///
+	pub fn position<P>(&self, mut predicate: P) -> Option<usize>
+	where
+		P: FnMut(T) -> bool,
+	{
+		// Look up the far address (if any) only once for all the loads below
+		let pm = self.resolved();
+
+		let len = pm.target.len();
+		let elem_size = size_of::<T>();
+
+		// Elements that do not fit into the buffer are loaded one at a time
+		if elem_size == 0
+			|| elem_size > SEARCH_BUFFER_SIZE
+			|| align_of::<T>() > align_of::<SearchBuffer>()
+		{
+			for idx in 0..len {
+				if predicate(pm.load_at(idx)) {
+					return Some(idx);
+				}
+			}
+			return None;
+		}
+
+		let chunk_len = SEARCH_BUFFER_SIZE / elem_size;
+		let first_element_ptr: *const T = pm.target.cast();
+		let mut buffer = SearchBuffer([MaybeUninit::uninit(); SEARCH_BUFFER_SIZE]);
+
+		let mut start = 0;
+		while start < len {
+			let n = chunk_len.min(len - start);
+
+			// SAFETY: the elements `start..start + n` are within the slice,
+			// which is in the progmem domain, and the buffer has room for
+			// `n` elements. We load them just as bytes, so padding is fine.
+			unsafe {
+				pm.read_slice_at(
+					first_element_ptr.wrapping_add(start).cast(),
+					&mut buffer.0[..n * elem_size],
+				)
+			};
+
+			for i in 0..n {
+				// SAFETY: the buffer is aligned for `T` (checked above), and
+				// its first `n` elements have just been loaded.
+				let elem = unsafe { buffer.0.as_ptr().cast::<T>().add(i).read() };
+
+				if predicate(elem) {
+					return Some(start + i);
+				}
+			}
+
+			start += n;
+		}
+
+		None
+	}
+
+	/// Returns whether the slice contains an element with the given value.
+	///
+	/// See [`position`](Self::position).
+	///
+	pub fn contains(&self, x: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		self.position(|elem| elem == *x).is_some()
+	}
+
+	/// Binary searches this sorted slice with a comparator function.
+	///
+	/// This method is analog to [`slice::binary_search_by`], but it loads
+	/// only the probed elements from progmem, one at a time.
+	///
+	/// The comparator function should return an order code that indicates
+	/// whether its argument is `Less`, `Equal` or `Greater` the desired
+	/// target.
+	/// If the slice is not sorted (according to the comparator), the
+	/// returned result is unspecified and meaningless.
+	///
+	/// If the value is found then `Ok` is returned, containing the index of
+	/// the matching element (if there are multiple matches, then any one of
+	/// them could be returned).
+	/// If the value is not found then `Err` is returned, containing the index
+	/// where a matching element could be inserted while maintaining sorted
+	/// order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem KEYS: [u8; 5] = [2, 3, 5, 7, 11];
+	/// }
+	///
+	/// let s: ProgMem<[u8]> = KEYS.as_slice();
+	///
+	/// assert_eq!(Ok(2), s.binary_search_by(|k| k.cmp(&5)));
+	/// assert_eq!(Err(3), s.binary_search_by(|k| k.cmp(&6)));
+	/// ```
+	///
+	pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+	where
+		F: FnMut(T) -> Ordering,
+	{
+		// Look up the far address (if any) only once for all the probes
+		let pm = self.resolved();
+
+		// Same as `core`, the element at `right` is known to be greater
+		let mut left = 0;
+		let mut right = pm.len();
+
+		while left < right {
+			let mid = left + (right - left) / 2;
+
+			match f(pm.load_at(mid)) {
+				Ordering::Less => left = mid + 1,
+				Ordering::Greater => right = mid,
+				Ordering::Equal => return Ok(mid),
+			}
+		}
+
+		Err(left)
+	}
+
+	/// Binary searches this sorted slice for a given element.
+	///
+	/// This method is analog to [`slice::binary_search`], see
+	/// [`binary_search_by`](Self::binary_search_by).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem KEYS: [u16; 4] = [10, 20, 30, 40];
+	/// }
+	///
+	/// let s: ProgMem<[u16]> = KEYS.as_slice();
+	///
+	/// assert_eq!(Ok(1), s.binary_search(&20));
+	/// assert_eq!(Err(0), s.binary_search(&5));
+	/// assert_eq!(Err(4), s.binary_search(&50));
+	/// ```
+	///
+	pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+	where
+		T: Ord,
+	{
+		self.binary_search_by(|elem| elem.cmp(x))
+	}
+
+	/// Binary searches this sorted slice with a key extraction function.
+	///
+	/// This method is analog to [`slice::binary_search_by_key`], see
+	/// [`binary_search_by`](Self::binary_search_by).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    /// Error codes with their messages, sorted by code
+	///	    static progmem ERRORS: [(u8, u8); 3] = [(1, b'a'), (4, b'b'), (9, b'c')];
+	/// }
+	///
+	/// let s: ProgMem<[(u8, u8)]> = ERRORS.as_slice();
+	///
+	/// assert_eq!(Ok(1), s.binary_search_by_key(&4, |(code, _)| code));
+	/// assert_eq!(Err(2), s.binary_search_by_key(&5, |(code, _)| code));
+	/// ```
+	///
+	pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+	where
+		F: FnMut(T) -> B,
+		B: Ord,
+	{
+		self.binary_search_by(|elem| f(elem).cmp(b))
+	}
+}
+
+impl<T: BytewiseEq> ProgMem<[T]> {
+	/// Returns whether the inner slice is equal to the given one.
+	///
+	/// This method is analog to the [`eq_slice`](ProgMem::eq_slice) method
+	/// of arrays, it compares the elements directly in progmem.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem ARR: [u16; 4] = [1,2,3,4];
+	/// }
+	///
+	/// assert!(ARR.as_slice().eq_slice(&[1,2,3,4]));
+	/// assert!(!ARR.as_slice().eq_slice(&[1,2,3]));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner slice is 64 KiB or larger.
+	///
+	pub fn eq_slice(&self, data: &[T]) -> bool {
+		if data.len() != self.len() {
+			return false;
+		}
+
+		// SAFETY: `BytewiseEq` types have no padding, thus all their bytes
+		// are initialized and the slice may be viewed as bytes.
+		let bytes =
+			unsafe { core::slice::from_raw_parts(data.as_ptr().cast::<u8>(), size_of_val(data)) };
+
+		// SAFETY: `self.target` points to a `[T]` in progmem, which has the
+		// same length as `data`, as checked above.
+		let ord = unsafe { self.compare_bytes_at(self.target.cast(), bytes) };
+
+		ord == Ordering::Equal
+	}
+}
+
+/// Utilities to compare a byte slice wrapper against bytes in RAM.
+impl ProgMem<[u8]> {
+	/// Compares the inner byte slice lexicographically with the given bytes.
+	///
+	/// This method is analog to the [`cmp_slice`](ProgMem::cmp_slice)
+	/// method of arrays, it compares the bytes directly in progmem, stopping
+	/// at the first mismatch.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::progmem;
+	/// use core::cmp::Ordering;
+	///
+	/// progmem!{
+	///     static progmem ARR: [u8; 11] = *b"Hello World";
+	/// }
+	///
+	/// let hello = ARR.as_slice().sub_slice(0..5);
+	/// assert_eq!(Ordering::Equal, hello.cmp_slice(b"Hello"));
+	/// assert_eq!(Ordering::Less, hello.cmp_slice(b"World"));
+	/// assert_eq!(Ordering::Less, hello.cmp_slice(b"Hello World"));
+	/// assert_eq!(Ordering::Greater, hello.cmp_slice(b"Hell"));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the inner slice is 64 KiB or larger.
+	///
+	pub fn cmp_slice(&self, data: &[u8]) -> Ordering {
+		let len = self.len();
+		let common = data.len().min(len);
+
+		// SAFETY: `self.target` points to a `[u8]` of `len` bytes in
+		// progmem, and we compare at most `len` bytes.
+		let ord = unsafe { self.compare_bytes_at(self.target.cast(), &data[..common]) };
+
+		// If the common prefix is equal, the shorter one is the lesser
+		ord.then(len.cmp(&data.len()))
+	}
+}
+
+/// Utilities to search in a byte slice wrapper.
+impl ProgMem<[u8]> {
+	/// Returns the index of the first occurrence of the given byte.
+	///
+	/// This method is analog to `memchr_P` of the avr-libc, it scans the
+	/// bytes directly in progmem via [`raw::find_byte`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use avr_progmem::wrapper::ProgMem;
+	/// use avr_progmem::progmem;
+	///
+	/// progmem!{
+	///	    static progmem TEXT: [u8; 11] = *b"a,b,c;d,e,f";
+	/// }
+	///
+	/// let s: ProgMem<[u8]> = TEXT.as_slice();
+	///
+	/// assert_eq!(Some(1), s.find_byte(b','));
+	/// assert_eq!(Some(9), s.rfind_byte(b','));
+	/// assert_eq!(Some(5), s.rfind_byte(b';'));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// This method panics, if the slice is 64 KiB or larger.
+	///
+	pub fn find_byte(&self, needle: u8) -> Option<usize> {
+		// SAFETY: the invariant of this struct guarantees that `target` points
+		// to a valid slice in the progmem domain.
+		unsafe {
+			match self.far_addr(self.target) {
+				Some(addr) => raw::find_byte_far(addr, self.len(), needle),
+				None => raw::find_byte(self.target.cast(), self.len(), needle),
+			}
+		}
+	}
+
+	/// Returns the index of the last occurrence of the given byte.
+	///
+	/// This method is analog to [`find_byte`](Self::find_byte), but scans
+	/// the bytes backwards via [`raw::rfind_byte`].
+	///
+	/// # Panics
+	///
+	/// This method panics, if the slice is 64 KiB or larger.
+	///
+	pub fn rfind_byte(&self, needle: u8) -> Option<usize> {
+		// SAFETY: the invariant of this struct guarantees that `target` points
+		// to a valid slice in the progmem domain.
+		unsafe {
+			match self.far_addr(self.target) {
+				Some(addr) => raw::rfind_byte_far(addr, self.len(), needle),
+				None => raw::rfind_byte(self.target.cast(), self.len(), needle),
+			}
+		}
+	}
+}
+
+
+/// Allows coercing a `ProgMem<T>` to a `ProgMem<U>`, where U might be unsized.
+///
+/// A classic example of this is coercing an array `ProgMem<[T; N]>` into a
+/// slice `ProgMem<[T]>`. Thus this impl is a generalization of the
+/// [`as_slice`][ProgMem::as_slice] method.
+///
+/// # Examples
+///
+/// ```rust
+/// use avr_progmem::wrapper::ProgMem;
+/// use avr_progmem::progmem;
+///
+/// progmem!{
+///	    static progmem ARR: [u8; 3] = [1,2,3];
+/// }
+///
+/// // The array wrapper
+/// let arr: ProgMem<[u8; 3]> = ARR;
+/// // Coerced to a slice wrapper, just like that.
+/// let s: ProgMem<[u8]> = arr;
+/// ```
+#[cfg(feature = "unsize")]
+impl<T: ?Sized, U: ?Sized> core::ops::CoerceUnsized<ProgMem<U>> for ProgMem<T> where
+	T: core::marker::Unsize<U>
+{
+}
+
+/// A pointer to a value in progmem, which can itself be stored in progmem.
+///
+/// This type is the equivalent of the classic
+/// `const char* const table[] PROGMEM` of the avr-libc: it allows to build
+/// tables in progmem, whose entries point to other progmem data, such as
+/// tables of strings or linked menu nodes.
+///
+/// A `PmRef` can be created in a `const` context from any [`ProgMem`], e.g.
+/// from a static of the [`progmem`] macro, thus it can be used in the
+/// initializer of another progmem static.
+/// When such a `PmRef` is loaded from progmem, it can be turned back into
+/// a `ProgMem` via [`get`](Self::get), or directly via
+/// [`ProgMem::follow`].
+///
+/// Notice, that this type is just a pointer, so it is always `Copy`,
+/// regardless of `T`. And unlike references, it is accepted by the
+/// `progmem` macro.
+///
+/// # Examples
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::wrapper::PmRef;
+///
+/// progmem!{
+///     static progmem RED: [u8; 3] = *b"red";
+///     static progmem GREEN: [u8; 5] = *b"green";
+///     static progmem BLUE: [u8; 4] = *b"blue";
+///
+///     /// A table of pointers to the above progmem data, all in progmem.
+///     static progmem COLORS: [PmRef<[u8]>; 3] = [
+///         PmRef::new(RED.as_slice()),
+///         PmRef::new(GREEN.as_slice()),
+///         PmRef::new(BLUE.as_slice()),
+///     ];
+/// }
+///
+/// // Load the pointer, and then the data it points to
+/// let green = COLORS.load_at(1).get();
+/// assert_eq!(5, green.len());
+/// assert_eq!(b'g', green.load_at(0));
+///
+/// // Or follow the pointer without loading it explicitly
+/// assert_eq!(b'b', COLORS.at(2).follow().load_at(0));
+/// ```
+//
+// This is just a pointer type/wrapper thus it is safe & sound to just copy it.
+#[derive(Derivative)]
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+#[repr(transparent)]
+pub struct PmRef<T: ?Sized> {
+	/// The wrapper of the referenced value.
+	///
+	/// Since it is a valid `ProgMem`, it points into the progmem domain.
+	target: ProgMem<T>,
+}
+
+/// Implement `uDebug` by hand, analog to `ProgMem`.
+#[cfg(feature = "ufmt")]
+impl<T: ?Sized> ufmt::uDebug for PmRef<T> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("PmRef")?
+			.field("target", &self.target)?
+			.finish()
+	}
+}
+
+impl<T: ?Sized> PmRef<T> {
+	/// Creates a pointer to the value of the given progmem wrapper.
+	///
+	/// This is a `const fn`, thus it can be used in the initializer of a
+	/// progmem static.
+	pub const fn new(pm: ProgMem<T>) -> Self {
+		Self {
+			target: pm,
+		}
+	}
+
+	/// Returns the wrapper of the referenced value.
+	pub const fn get(&self) -> ProgMem<T> {
+		self.target
+	}
+}
+
+impl<T: ?Sized> From<ProgMem<T>> for PmRef<T> {
+	fn from(pm: ProgMem<T>) -> Self {
+		Self::new(pm)
+	}
+}
+
+impl<T: ?Sized> From<PmRef<T>> for ProgMem<T> {
+	fn from(pm_ref: PmRef<T>) -> Self {
+		pm_ref.get()
+	}
+}
+
+/// Following pointers stored in progmem.
+impl<T: ?Sized> ProgMem<PmRef<T>> {
+	/// Loads the pointer and returns the wrapper of the referenced value.
+	///
+	/// This is a shorthand for `self.load().get()`.
+	///
+	pub fn follow(&self) -> ProgMem<T> {
+		self.load().get()
+	}
+}
+
+
+/// An iterator over an array in progmem.
+///
+/// Can be acquired via [`ProgMem::iter`].
+///
+/// This iterator can also be used from the back (e.g. via `rev`), and it
+/// skips elements (e.g. via `nth` or `skip`) without loading them.
+///
+/// # Example
+///
+/// ```rust
+/// use avr_progmem::progmem;
+///
+/// progmem! {
+///     static progmem SAMPLES: [u8; 6] = [1, 2, 3, 4, 5, 6];
+/// }
+///
+/// let mut iter = SAMPLES.iter();
+/// assert_eq!(6, iter.len());
+/// assert_eq!(Some(6), iter.next_back());
+/// assert_eq!(Some(3), iter.nth(2));
+/// assert_eq!(vec![5, 4], iter.rev().collect::<Vec<_>>());
+/// ```
+pub struct PmIter<'a, T, const N: usize> {
+	/// A copy of the array wrapper, whose far address (if any) gets resolved
+	/// by the first load
+	progmem: ProgMem<[T; N]>,
+	/// The index of the next element from the front
+	current_idx: usize,
+	/// The index after the next element from the back
+	end_idx: usize,
+	/// Logically, we borrow the array wrapper
+	marker: PhantomData<&'a ProgMem<[T; N]>>,
+}
+
+impl<'a, T, const N: usize> PmIter<'a, T, N> {
+	/// Creates a new iterator over the given progmem array.
+	pub const fn new(pm: &'a ProgMem<[T; N]>) -> Self {
+		Self {
+			progmem: *pm,
+			current_idx: 0,
+			end_idx: N,
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<'a, T: Copy, const N: usize> PmIter<'a, T, N> {
+	/// Loads the element at `idx`, resolving the far address (if any) on
+	/// the first call.
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn load_at(&mut self, idx: usize) -> T {
+		self.progmem = self.progmem.resolved();
+		self.progmem.load_at(idx)
+	}
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for PmIter<'a, T, N> {
+	type Item = T;
+
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next(&mut self) -> Option<Self::Item> {
+		// Check for iterator end
+		if self.current_idx < self.end_idx {
+			// Load next item from progmem
+			let b = self.load_at(self.current_idx);
+			self.current_idx += 1;
+
+			Some(b)
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end_idx - self.current_idx;
+		(len, Some(len))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		// Skip the elements without loading them
+		self.current_idx = self.current_idx.saturating_add(n).min(self.end_idx);
+		self.next()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<'a, T: Copy, const N: usize> DoubleEndedIterator for PmIter<'a, T, N> {
+	#[cfg_attr(all(feature = "instrument", not(target_arch = "avr")), track_caller)]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		// Check for iterator end
+		if self.current_idx < self.end_idx {
+			// Load next item from the back
+			self.end_idx -= 1;
+			Some(self.load_at(self.end_idx))
+		} else {
+			None
+		}
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		// Skip the elements without loading them
+		self.end_idx = self.end_idx.saturating_sub(n).max(self.current_idx);
+		self.next_back()
+	}
+}
+
+impl<'a, T: Copy, const N: usize> ExactSizeIterator for PmIter<'a, T, N> {}
+
+impl<'a, T: Copy, const N: usize> FusedIterator for PmIter<'a, T, N> {}
+
+/// Same as [`ProgMem::iter`]
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a ProgMem<[T; N]> {
+	type IntoIter = PmIter<'a, T, N>;
+	type Item = T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An iterator over an array in progmem, without loading elements
+///
+/// Can be acquired via [`ProgMem::wrapper_iter`].
+///
+/// Like [`PmIter`], this iterator can also be used from the back, and it
+/// skips elements efficiently.
+pub struct PmWrapperIter<T> {
+	/// The remaining elements, which shrinks from both ends
+	progmem: ProgMem<[T]>,
+}
+
+impl<T> PmWrapperIter<T> {
+	/// Creates a new iterator over the given progmem array.
+	pub const fn new(pm: ProgMem<[T]>) -> Self {
+		Self {
+			progmem: pm,
+		}
+	}
+}
+
+impl<T> Iterator for PmWrapperIter<T> {
+	type Item = ProgMem<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.progmem.len();
+
+		// Check for iterator end
+		if len > 0 {
+			// Get next element wrapper
+			let b = self.progmem.at(0);
+			self.progmem = self.progmem.sub_slice(1..len);
+
+			Some(b)
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.progmem.len();
+		(len, Some(len))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let len = self.progmem.len();
+		self.progmem = self.progmem.sub_slice(n.min(len)..len);
+		self.next()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T> DoubleEndedIterator for PmWrapperIter<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let len = self.progmem.len();
+
+		// Check for iterator end
+		if len > 0 {
+			// Get next element wrapper from the back
+			let b = self.progmem.at(len - 1);
+			self.progmem = self.progmem.sub_slice(0..(len - 1));
+
+			Some(b)
+		} else {
+			None
+		}
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let len = self.progmem.len();
+		self.progmem = self.progmem.sub_slice(0..len.saturating_sub(n));
+		self.next_back()
+	}
+}
+
+impl<T> ExactSizeIterator for PmWrapperIter<T> {}
+
+impl<T> FusedIterator for PmWrapperIter<T> {}
+
+
+
+/// Best-effort safe wrapper around a value anywhere in program memory.
+///
+/// This is the far counterpart of [`ProgMem`].
+/// While a [`ProgMem`] just wraps a normal pointer, which on AVR is limited
+/// to the lower 64 KiB of program memory, this type can access values
+/// located anywhere in the program memory, e.g. beyond the 64 KiB limit on an
+/// ATmega2560.
+/// Consequently, this type uses the `elpm` instruction, which is only
+/// available on AVR devices with more than 64 KiB of flash.
+///
+/// Since the full address of a value beyond the 64 KiB limit can not be
+/// expressed as normal pointer on AVR, this type instead wraps a function that
+/// yields the [`FarAddr`] of the underlying `static`.
+/// The easiest way to create such a wrapper is the `far` rule of the
+/// [`progmem`] macro, which will create that function for you.
+/// However, the wrappers of elements and sub-slices as well as the iterators
+/// keep the resolved address, so they do not call that function again.
+///
+/// Like [`ProgMem`], this is just a fancy pointer type, so it can always be
+/// copied/cloned.
+/// And like a `ProgMem<[T; N]>`, an array wrapper can be turned into a slice
+/// wrapper (via [`as_slice`](ProgMemFar::as_slice)), which can be further
+/// sub-sliced and iterated.
+///
+///
+/// # Safety
+///
+/// The `addr` must always be (or yield) the far address of a valid object of
+/// type `T` stored in the program memory domain.
+/// If `T` is a slice, it consists of `len` many elements.
+/// The object must be initialized, readable, and immutable (i.e. it must not
+/// be changed).
+///
+/// Same as for [`ProgMem`], the requirement about the program memory domain
+/// only applies to the AVR architecture, on all other architectures, the
+/// object must be in the regular data memory domain.
+///
+//
+//
+// SAFETY: Must not be publicly creatable
+#[non_exhaustive]
+//
+// We use Derivative here to get rid of the constraint on the impls, which
+// a normal derive would add.
+#[derive(Derivative)]
+// This is just a pointer type/wrapper thus it is safe & sound to just copy it.
+#[derivative(Copy(bound = ""), Clone(bound = ""), Debug(bound = ""))]
+pub struct ProgMemFar<T: ?Sized> {
+	/// The far address of some object in progmem.
+	///
+	/// # Safety
+	///
+	/// See the struct doc.
+	addr: FarBase,
+	/// The number of elements, if `T` is a slice, unused otherwise.
+	///
+	/// # Safety
+	///
+	/// See the struct doc.
+	len: usize,
+	/// Logically, we point to some `T`
+	marker: PhantomData<*const T>,
+}
+
+/// The far address of a [`ProgMemFar`].
+#[derive(Copy, Clone, Debug)]
+enum FarBase {
+	/// The address of a `static`, as yielded by the given function.
+	///
+	/// The function can not be called in a `const` context, thus this is
+	/// what the `far` rule of the [`progmem`] macro starts with.
+	Static(fn() -> FarAddr),
+	/// An already resolved address.
+	Resolved(FarAddr),
+}
+
+impl FarBase {
+	/// Returns the far address.
+	fn resolve(self) -> FarAddr {
+		match self {
+			FarBase::Static(base) => base(),
+			FarBase::Resolved(addr) => addr,
+		}
+	}
+}
+
+/// The far address of a [`ProgMem`] with the "elpm" crate feature.
+///
+/// The far address of any pointer into the `static` is computed from its
+/// offset to the `origin`, i.e. the (possibly truncated) pointer to the
+/// `static` itself.
+#[cfg(feature = "elpm")]
+#[derive(Copy, Clone, Debug)]
+struct FarOrigin {
+	/// The far address of the `static`
+	base: FarBase,
+	/// The pointer to the `static`, as given to `ProgMem::new_far`
+	origin: *const u8,
+}
+
+#[cfg(feature = "elpm")]
+impl FarOrigin {
+	/// Returns this origin with its far address resolved.
+	fn resolved(self) -> Self {
+		FarOrigin {
+			base: FarBase::Resolved(self.base.resolve()),
+			origin: self.origin,
+		}
+	}
+
+	/// Returns the far address of the given pointer into the `static`.
+	fn addr_of(self, ptr: *const u8) -> FarAddr {
+		let offset = (ptr as usize).wrapping_sub(self.origin as usize);
+		self.base.resolve() + offset as FarAddr
+	}
+}
+
+/// Implement `uDebug` by hand, analog to `ProgMem`.
+#[cfg(feature = "ufmt")]
+impl<T: ?Sized> ufmt::uDebug for ProgMemFar<T> {
+	fn fmt<W>(&self, fmt: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+	where
+		W: ufmt::uWrite + ?Sized,
+	{
+		fmt.debug_struct("ProgMemFar")?
+			.field("addr", &self.addr())?
+			.finish()
+	}
+}
+
+unsafe impl<T: ?Sized> Send for ProgMemFar<T> {
+	// SAFETY: same as for `ProgMem`, this is essentially a pointer, and we
+	// never mutate the underling value.
+}
+
+unsafe impl<T: ?Sized> Sync for ProgMemFar<T> {
+	// SAFETY: same as for `ProgMem`, this is essentially a pointer, and we
+	// never mutate the underling value.
+}
+
+impl<T: ?Sized> ProgMemFar<T> {
+	/// Return the far address of the inner value.
+	///
+	/// Notice that the returned address is indeed an address into the
+	/// progmem domain!
+	/// It may only be used with the far "raw" functions, such as
+	/// [`read_value_far`].
+	///
+	pub fn addr(&self) -> FarAddr {
+		self.addr.resolve()
+	}
+
+	/// Returns the wrapper of a `U` located `offset` bytes into this value.
+	///
+	/// # Safety
+	///
+	/// The resulting address and `len` must fulfill the struct invariant for
+	/// `U`.
+	unsafe fn offset<U: ?Sized>(&self, offset: usize, len: usize) -> ProgMemFar<U> {
+		ProgMemFar {
+			addr: FarBase::Resolved(self.addr() + offset as FarAddr),
+			len,
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<T> ProgMemFar<T> {
+	/// Construct a new instance of this type.
+	///
+	/// You should not need to call this function directly.
+	/// It is recommended to use the `far` rule of the [`progmem`] macro
+	/// instead.
+	///
+	///
+	/// # Safety
+	///
+	/// The given `base` function must always return the far address of a
+	/// valid `T` that is stored in a `static` in progmem,
+	/// e.g. by using the attribute `#[link_section = ".progmemx.data"]`.
+	///
+	/// However, the above requirement about the program memory domain only
+	/// applies to the AVR architecture (`#[cfg(target_arch = "avr")]`),
+	/// otherwise the address needs to point to normal data on those
+	/// architectures.
+	///
+	pub const unsafe fn new(base: fn() -> FarAddr) -> Self {
+		ProgMemFar {
+			addr: FarBase::Static(base),
+			len: 0,
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<T: Copy> ProgMemFar<T> {
+	/// Read the inner value from progmem and return a regular value.
+	pub fn load(&self) -> T {
+		// This is safe, because the invariant of this struct guarantees that
+		// this address is the address of a `T` in the progmem domain,
+		// which is what `read_value_far` requires from us.
+		unsafe { read_value_far(self.addr()) }
+	}
+}
+
+/// Utilities to work with an array anywhere in progmem.
+impl<T, const N: usize> ProgMemFar<[T; N]> {
+	/// Get a wrapper to an element from the array, without loading it.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the array.
+	pub fn at(&self, idx: usize) -> ProgMemFar<T> {
+		self.as_slice().at(idx)
+	}
+
+	/// Returns the length of the array (i.e. `N`)
+	pub fn len(&self) -> usize {
+		N
+	}
+
+	/// Returns whether the array is empty (i.e. `N == 0`)
+	pub fn is_empty(&self) -> bool {
+		N == 0
+	}
+
+	/// Returns a wrapper of the array as slice, without loading it.
+	///
+	/// This is the far variant of [`ProgMem::as_slice`].
+	pub const fn as_slice(&self) -> ProgMemFar<[T]> {
+		// This is sound, because the slice consists of exactly the `N`
+		// elements of the array.
+		ProgMemFar {
+			addr: self.addr,
+			len: N,
+			marker: PhantomData,
+		}
+	}
+}
+
+/// Loading elements of an array anywhere in progmem.
+impl<T: Copy, const N: usize> ProgMemFar<[T; N]> {
+	/// Load a single element from the inner array.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the inner type.
+	///
+	pub fn load_at(&self, idx: usize) -> T {
+		// Just get the element wrapper and load it
+		self.at(idx).load()
+	}
+
+	/// Loads a sub array from the inner array.
+	///
+	/// This is the far variant of [`ProgMem::load_sub_array`].
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length `N` of the inner array, or the end index `idx+M` is grater than
+	/// the length `N` of the inner array.
+	///
+	pub fn load_sub_array<const M: usize>(&self, start_idx: usize) -> [T; M] {
+		// Just a check to give a nicer panic message
+		assert!(
+			M <= N,
+			"The sub array can not be grater than the source array"
+		);
+
+		// SAFETY: bounds check, the last element of the sub array must
+		// still be within the source array (i.e. self)
+		assert!(
+			start_idx + M <= N,
+			"The sub array goes beyond the end of the source array"
+		);
+
+		let addr = self.addr() + (start_idx * size_of::<T>()) as FarAddr;
+
+		// SAFETY: This is safe, because the invariant of this struct demands
+		// that the array is stored in the progmem domain, and we checked that
+		// the sub array is within that array.
+		unsafe { read_value_far(addr) }
+	}
+
+	/// Lazily iterate over all elements
+	///
+	/// Returns an iterator which lazily loads the elements one at a time
+	/// from progmem.
+	/// This means this iterator can be used to access huge arrays while
+	/// only requiring `size_of::<T>()` amount of stack memory.
+	///
+	/// This is the far variant of [`ProgMem::iter`], see [`PmFarIter`].
+	pub fn iter(&self) -> PmFarIter<T> {
+		self.as_slice().iter()
+	}
+}
+
+/// Same as [`ProgMemFar::iter`]
+impl<T: Copy, const N: usize> IntoIterator for &ProgMemFar<[T; N]> {
+	type IntoIter = PmFarIter<T>;
+	type Item = T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
 }
 
-/// Loading elements of an array in progmem.
-impl<T: Copy> ProgMem<[T]> {
-	/// Load a single element from the slice.
-	///
-	/// This method is analog to a slice indexing, thus the same requirements
-	/// apply: the index `idx` should be less then the length of the slice,
-	/// otherwise a panic will be risen.
-	///
+/// Utilities to work with a slice anywhere in progmem.
+///
+/// You can obtain a slice wrapper via [`ProgMemFar::as_slice`].
+impl<T> ProgMemFar<[T]> {
+	/// Get a wrapper to an element from the slice, without loading it.
 	///
 	/// # Panics
 	///
 	/// This method panics, if the given index `idx` is grater or equal to the
 	/// length of the slice.
+	pub fn at(&self, idx: usize) -> ProgMemFar<T> {
+		// SAFETY: check that `idx` is in bounds
+		assert!(idx < self.len, "Given index is out of bounds");
+
+		// This sound, because we checked above that `idx` is in bound, thus
+		// the offset still points to an element within the slice.
+		unsafe { self.offset(idx * size_of::<T>(), 0) }
+	}
+
+	/// Get a wrapper to a sub-slice, without loading it.
 	///
-	/// This method also panics, if the size of the value (i.e. `size_of::<T>()`)
-	/// is beyond 255 bytes.
-	/// However, this is currently just a implementation limitation, which may
-	/// be lifted in the future.
+	/// # Panics
 	///
-	/// Notice, that here `T` is the type of the elements not the entire slice.
+	/// This method panics, if the given `range` is not within the bounds of
+	/// the slice.
+	pub fn sub_slice(&self, range: Range<usize>) -> ProgMemFar<[T]> {
+		// SAFETY: bounds check, the range must be within the slice
+		assert!(
+			range.start <= range.end && range.end <= self.len,
+			"The range goes beyond the end of the slice"
+		);
+
+		// This sound, because we checked above that the range is in bound,
+		// thus that sub slice is also within the slice.
+		unsafe { self.offset(range.start * size_of::<T>(), range.len()) }
+	}
+
+	/// Returns the length of the slice
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the slice is empty
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+}
+
+/// Loading elements of a slice anywhere in progmem.
+impl<T: Copy> ProgMemFar<[T]> {
+	/// Load a single element from the slice.
+	///
+	/// # Panics
+	///
+	/// This method panics, if the given index `idx` is grater or equal to the
+	/// length of the slice.
 	///
 	pub fn load_at(&self, idx: usize) -> T {
 		// Just get the element wrapper and load it
 		self.at(idx).load()
 	}
-}
 
+	/// Lazily iterate over all elements
+	///
+	/// See [`ProgMemFar::iter`] of arrays.
+	pub fn iter(&self) -> PmFarIter<T> {
+		PmFarIter::new(*self)
+	}
+}
 
-/// Allows coercing a `ProgMem<T>` to a `ProgMem<U>`, where U might be unsized.
+/// An iterator over an array or slice anywhere in progmem.
 ///
-/// A classic example of this is coercing an array `ProgMem<[T; N]>` into a
-/// slice `ProgMem<[T]>`. Thus this impl is a generalization of the
-/// [`as_slice`][ProgMem::as_slice] method.
+/// Can be acquired via [`ProgMemFar::iter`].
 ///
-/// # Examples
+/// This is the far counterpart of [`PmIter`], thus it can also be used from
+/// the back, and it skips elements without loading them.
+/// It resolves the address of the slice once, when it is created.
+///
+/// # Example
 ///
 /// ```rust
-/// use avr_progmem::wrapper::ProgMem;
 /// use avr_progmem::progmem;
 ///
-/// progmem!{
-///	    static progmem ARR: [u8; 3] = [1,2,3];
+/// progmem! {
+///     static progmem far SAMPLES: [u8; 6] = [1, 2, 3, 4, 5, 6];
 /// }
 ///
-/// // The array wrapper
-/// let arr: ProgMem<[u8; 3]> = ARR;
-/// // Coerced to a slice wrapper, just like that.
-/// let s: ProgMem<[u8]> = arr;
-/// ```
-#[cfg(feature = "unsize")]
-impl<T: ?Sized, U: ?Sized> core::ops::CoerceUnsized<ProgMem<U>> for ProgMem<T> where
-	T: core::marker::Unsize<U>
-{
-}
-
-/// An iterator over an array in progmem.
+/// let mut iter = SAMPLES.iter();
+/// assert_eq!(6, iter.len());
+/// assert_eq!(Some(6), iter.next_back());
+/// assert_eq!(Some(3), iter.nth(2));
+/// assert_eq!(vec![5, 4], iter.rev().collect::<Vec<_>>());
 ///
-/// Can be acquired via [`ProgMem::iter`].
-pub struct PmIter<'a, T, const N: usize> {
-	progmem: &'a ProgMem<[T; N]>,
+/// // Also works on sub-slices
+/// let sum: u8 = SAMPLES.as_slice().sub_slice(1..4).iter().sum();
+/// assert_eq!(9, sum);
+/// ```
+pub struct PmFarIter<T> {
+	progmem: ProgMemFar<[T]>,
+	/// The index of the next element from the front
 	current_idx: usize,
+	/// The index after the next element from the back
+	end_idx: usize,
 }
 
-impl<'a, T, const N: usize> PmIter<'a, T, N> {
-	/// Creates a new iterator over the given progmem array.
-	pub const fn new(pm: &'a ProgMem<[T; N]>) -> Self {
+impl<T> PmFarIter<T> {
+	/// Creates a new iterator over the given progmem slice.
+	pub fn new(pm: ProgMemFar<[T]>) -> Self {
 		Self {
-			progmem: pm,
+			// SAFETY: this is the entire slice, just with a resolved address
+			progmem: unsafe { pm.offset(0, pm.len) },
 			current_idx: 0,
+			end_idx: pm.len,
 		}
 	}
 }
 
-impl<'a, T: Copy, const N: usize> Iterator for PmIter<'a, T, N> {
+impl<T: Copy> Iterator for PmFarIter<T> {
 	type Item = T;
 
 	fn next(&mut self) -> Option<Self::Item> {
 		// Check for iterator end
-		if self.current_idx < N {
+		if self.current_idx < self.end_idx {
 			// Load next item from progmem
 			let b = self.progmem.load_at(self.current_idx);
 			self.current_idx += 1;
@@ -503,53 +2176,46 @@ impl<'a, T: Copy, const N: usize> Iterator for PmIter<'a, T, N> {
 			None
 		}
 	}
-}
 
-/// Same as [`ProgMem::iter`]
-impl<'a, T: Copy, const N: usize> IntoIterator for &'a ProgMem<[T; N]> {
-	type IntoIter = PmIter<'a, T, N>;
-	type Item = T;
-
-	fn into_iter(self) -> Self::IntoIter {
-		self.iter()
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end_idx - self.current_idx;
+		(len, Some(len))
 	}
-}
 
-/// An iterator over an array in progmem, without loading elements
-///
-/// Can be acquired via [`ProgMem::wrapper_iter`].
-pub struct PmWrapperIter<T> {
-	progmem: ProgMem<[T]>,
-	current_idx: usize,
-}
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		// Skip the elements without loading them
+		self.current_idx = self.current_idx.saturating_add(n).min(self.end_idx);
+		self.next()
+	}
 
-impl<T> PmWrapperIter<T> {
-	/// Creates a new iterator over the given progmem array.
-	pub const fn new(pm: ProgMem<[T]>) -> Self {
-		Self {
-			progmem: pm,
-			current_idx: 0,
-		}
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-impl<T> Iterator for PmWrapperIter<T> {
-	type Item = ProgMem<T>;
-
-	fn next(&mut self) -> Option<Self::Item> {
+impl<T: Copy> DoubleEndedIterator for PmFarIter<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
 		// Check for iterator end
-		if self.current_idx < self.progmem.len() {
-			// Get next element wrapper
-			let b = self.progmem.at(self.current_idx);
-			self.current_idx += 1;
-
-			Some(b)
+		if self.current_idx < self.end_idx {
+			// Load next item from the back
+			self.end_idx -= 1;
+			Some(self.progmem.load_at(self.end_idx))
 		} else {
 			None
 		}
 	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		// Skip the elements without loading them
+		self.end_idx = self.end_idx.saturating_sub(n).max(self.current_idx);
+		self.next_back()
+	}
 }
 
+impl<T: Copy> ExactSizeIterator for PmFarIter<T> {}
+
+impl<T: Copy> FusedIterator for PmFarIter<T> {}
+
 
 
 /// Define a static in progmem.
@@ -568,6 +2234,9 @@ impl<T> Iterator for PmWrapperIter<T> {
 /// * "auto-sized" arrays, essentially any kind of array `[T; N]`
 /// * strings, i.e. anything `str`-ish such as string literals
 ///
+/// Additionally, ordinary data and arrays may also be stored as "far" data,
+/// see the [Far Data](#far-data) section.
+///
 ///
 /// # Ordinary Data
 ///
@@ -609,7 +2278,8 @@ impl<T> Iterator for PmWrapperIter<T> {
 /// Notice, that to access ordinary data from the progmem you have to load it
 /// as whole before you can do anything with it.
 /// In other words you can't just load `foo.a`, you have to first load the
-/// entire struct into RAM.
+/// entire struct into RAM, unless you use the [`pm_field`] macro, which gives
+/// you a `ProgMem` of just that field.
 ///
 /// When we have arrays, stuff can get hugh quickly, therefore,
 /// specifically for arrays, we have additionally accessors to access elements
@@ -669,6 +2339,143 @@ impl<T> Iterator for PmWrapperIter<T> {
 /// assert_eq!(32, middle);
 /// ```
 ///
+/// # Sorted Tables
+///
+/// Lookup tables, which are searched via [`ProgMem::binary_search`], must be
+/// sorted in ascending order.
+/// With the `sorted` keyword, this is asserted at compile-time, i.e. an
+/// unsorted table is a compile error.
+/// Notice, that this assertion only works for arrays of primitive types such
+/// as integers, because it runs in a `const` context.
+///
+/// ```
+/// use avr_progmem::progmem;
+///
+/// progmem!{
+///     /// A sorted table of error IDs
+///     pub static progmem sorted ERROR_IDS: [u16; 4] = [3, 17, 42, 1000];
+///
+///     /// Also works with "auto-sized" arrays
+///     pub static progmem sorted <const CODES_LEN: usize> CODES: [u8; CODES_LEN] =
+///         [1, 1, 2, 3, 5, 8];
+/// }
+///
+/// assert_eq!(Ok(2), ERROR_IDS.binary_search(&42));
+/// assert_eq!(Err(CODES_LEN), CODES.binary_search(&9));
+/// ```
+///
+/// # Far Data
+///
+/// On AVR devices with more than 64 KiB of flash (such as the ATmega2560),
+/// big tables might end up beyond the 64 KiB limit, which can not be accessed
+/// via the normal [`ProgMem`] wrapper.
+/// For such data, you can use the `far` keyword, which yields a
+/// [`ProgMemFar`] instead, and places the data in the `.progmemx.data` section,
+/// which may be placed anywhere in the program memory.
+///
+/// Notice, that on AVR, the `far` rule emits a bit of inline assembly into
+/// your crate, thus you need to enable the `asm_experimental_arch` Rust feature
+/// (i.e. `#![feature(asm_experimental_arch)]`) in your crate.
+///
+/// ```
+/// use avr_progmem::progmem;
+///
+/// progmem!{
+///     /// A big table, which may be located anywhere in progmem
+///     pub static progmem far TABLE: [u16; 4] = [1, 2, 3, 4];
+///
+///     /// Also works with "auto-sized" arrays
+///     pub static progmem far <const DATA_LEN: usize> DATA: [u8; DATA_LEN] =
+///         *include_bytes!("../examples/test_text.txt");
+/// }
+///
+/// // Far data is accessed just like normal progmem data
+/// assert_eq!([1, 2, 3, 4], TABLE.load());
+/// assert_eq!(3, TABLE.load_at(2));
+/// assert_eq!([2, 3], TABLE.load_sub_array::<2>(1));
+/// assert_eq!(32, DATA.load_at(DATA_LEN / 2));
+///
+/// // Including slices and lazy iteration
+/// let tail = TABLE.as_slice().sub_slice(2..4);
+/// assert_eq!(7, tail.iter().sum::<u16>());
+/// ```
+///
+/// Strings can be stored as far data, too, using the `far string` keywords,
+/// which yields a [`PmFarString`](crate::string::PmFarString).
+///
+/// ```
+/// use avr_progmem::progmem;
+///
+/// progmem!{
+///     /// A text, which may be located anywhere in progmem
+///     static progmem far string TEXT = "dai 大賢者 kenja";
+/// }
+///
+/// assert_eq!("dai 大賢者 kenja", &*TEXT.load());
+/// assert_eq!(Some('大'), TEXT.chars().nth(4));
+/// assert_eq!("dai 大賢者 kenja", format!("{}", TEXT));
+/// ```
+///
+/// Alternatively, if you enable the "elpm" crate feature, all the other
+/// rules (except for the `page` rule) yield wrappers which load their data
+/// via `elpm`, too, so they keep working even if the data ended up beyond the
+/// 64 KiB limit.
+/// Same as the `far` rule, this requires the `asm_experimental_arch` Rust
+/// feature on AVR, and it must not be used on AVR devices without `elpm`.
+///
+/// # Pointers to Progmem Data
+///
+/// A progmem static may also contain pointers to other progmem data, e.g.
+/// a table of strings of different lengths.
+/// However, normal references are rejected by this macro (they would point
+/// into the data domain), instead use the [`PmRef`] type, which can be
+/// created from other progmem statics in a `const` context.
+///
+/// ```
+/// use avr_progmem::progmem;
+/// use avr_progmem::wrapper::PmRef;
+///
+/// progmem!{
+///     static progmem YES: [u8; 3] = *b"yes";
+///     static progmem NO: [u8; 2] = *b"no";
+///
+///     /// A table of pointers to progmem data, itself in progmem
+///     pub static progmem ANSWERS: [PmRef<[u8]>; 2] = [
+///         PmRef::new(YES.as_slice()),
+///         PmRef::new(NO.as_slice()),
+///     ];
+/// }
+///
+/// let no = ANSWERS.at(1).follow();
+/// assert_eq!(2, no.len());
+/// assert_eq!(b'o', no.load_at(1));
+/// ```
+///
+/// # Writable Pages
+///
+/// The program memory can be rewritten at runtime in units of pages (see the
+/// [`flash_write`](crate::flash_write) module).
+/// For this, you can use the `page` keyword, which yields a
+/// [`ProgMemPage`](crate::flash_write::ProgMemPage) instead, and places
+/// a page-aligned byte array in progmem.
+/// The size of the array must be the page size of your AVR chip
+/// (e.g. 128 bytes on the ATmega328P).
+///
+/// ```
+/// use avr_progmem::progmem;
+///
+/// progmem!{
+///     /// A page, which may be rewritten at runtime
+///     pub static progmem page SETTINGS: [u8; 128] = [0xFF; 128];
+/// }
+///
+/// assert_eq!(0xFF, SETTINGS.as_progmem().load_at(0));
+///
+/// // SAFETY: the page is not accessed otherwise while being written
+/// unsafe { SETTINGS.write(&[42; 128]) };
+/// assert_eq!(42, SETTINGS.as_progmem().load_at(0));
+/// ```
+///
 /// # Strings
 ///
 /// Strings are complicated, partially, because in Rust strings such as `str`
@@ -703,19 +2510,142 @@ impl<T> Iterator for PmWrapperIter<T> {
 /// assert_eq!("Unicode text: 大賢者", &*text);
 /// ```
 ///
+/// ## String Tables
+///
+/// Since the length of a `PmString` is part of its type, strings of
+/// different lengths can not be put into an array.
+/// Instead, the `strings` keyword creates a
+/// [`PmStringTable`](crate::string::PmStringTable) from a list of string
+/// literals, which stores them packed together with an index of their
+/// offsets in progmem.
+///
+/// ```rust
+/// use avr_progmem::progmem;
+///
+/// progmem! {
+///     /// Error messages indexed by their error code
+///     static progmem strings ERRORS = [
+///         "Ok",
+///         "Sensor failure",
+///         "Out of range",
+///     ];
+/// }
+///
+/// let msg = ERRORS.get(2).unwrap();
+/// assert_eq!(12, msg.len());
+/// assert!(ERRORS.get(3).is_none());
+/// ```
+///
 #[macro_export]
 macro_rules! progmem {
 	// Special string rule
 	(
 		$( #[ $attr:meta ] )*
-		$vis:vis static progmem string $name:ident = $value:expr ;
+		$vis:vis static progmem string $name:ident = $value:expr ;
+
+		$($rest:tt)*
+	) => {
+		// Just forward to internal rule
+		$crate::progmem_internal!{
+			$(#[$attr])*
+			$vis static progmem string $name = $value ;
+		}
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::progmem!{
+			$($rest)*
+		}
+	};
+
+	// Special string table rule
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem strings $name:ident = [ $( $value:expr ),* $(,)? ] ;
+
+		$($rest:tt)*
+	) => {
+		// Just forward to internal rule
+		$crate::progmem_internal!{
+			$(#[$attr])*
+			$vis static progmem strings $name = [ $( $value ),* ] ;
+		}
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::progmem!{
+			$($rest)*
+		}
+	};
+
+	// Catch "hand" strings rule, use the above special rule instead
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem $name:ident : $( avr_progmem::string:: )? LoadedString < $ty:literal > = $( avr_progmem::string:: )?  LoadedString :: new ( $value:expr ) $( . unwrap () $(@ $unwrapped:ident)? )? ;
+
+		$($rest:tt)*
+	) => {
+		// Make this a hard compile-time error.
+		::core::compile_error!("Prefer using the special `PmString` rule with the `string` keyword.");
+		::core::compile_error!(concat!("Use instead: ", stringify!($vis), " static progmem string ", stringify!($name), " = ..."));
+
+		// Emit a dummy to suppress errors where `$name` is used
+		static $name : $crate::wrapper::ProgMem< $crate::string::LoadedString< $ty > > = todo!();
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::progmem!{
+			$($rest)*
+		}
+	};
+
+	// Catch references rule, reference are evil!
+	// (well actually they are not, but most likely using them *is* a mistake)
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem $name:ident : & $ty:ty = $value:expr ;
+
+		$($rest:tt)*
+	) => {
+		// Make this a hard compile-time error
+		::core::compile_error!("Do not use a reference type for progmem, because this way only the reference itself would be in progmem, whereas the underlying data would still be in the normal data domain!");
+		::core::compile_error!("To point to other progmem data, use a `PmRef` instead.");
+
+		// Emit a dummy to suppress errors where `$name` is used
+		static $name : & $ty = todo!();
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::progmem!{
+			$($rest)*
+		}
+	};
+
+	// Far string rule
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem far string $name:ident = $value:expr ;
+
+		$($rest:tt)*
+	) => {
+		// Just forward to internal rule
+		$crate::progmem_internal!{
+			$(#[$attr])*
+			$vis static progmem far string $name = $value ;
+		}
+
+		// Recursive call to allow multiple items in macro invocation
+		$crate::progmem!{
+			$($rest)*
+		}
+	};
+
+	// Far rule
+	(
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem far $( < const $size_name:ident : usize > )? $name:ident : $ty:ty = $value:expr ;
 
 		$($rest:tt)*
 	) => {
-		// Just forward to internal rule
+		// Crate the far progmem static via internal macro
 		$crate::progmem_internal!{
-			$(#[$attr])*
-			$vis static progmem string $name = $value ;
+			$(#[$attr])* $vis static progmem far $( < const $size_name : usize > )? $name : $ty = $value;
 		}
 
 		// Recursive call to allow multiple items in macro invocation
@@ -724,19 +2654,17 @@ macro_rules! progmem {
 		}
 	};
 
-	// Catch "hand" strings rule, use the above special rule instead
+	// Page rule
 	(
 		$( #[ $attr:meta ] )*
-		$vis:vis static progmem $name:ident : $( avr_progmem::string:: )? LoadedString < $ty:literal > = $( avr_progmem::string:: )?  LoadedString :: new ( $value:expr ) $( . unwrap () $(@ $unwrapped:ident)? )? ;
+		$vis:vis static progmem page $name:ident : [ u8 ; $size:literal ] = $value:expr ;
 
 		$($rest:tt)*
 	) => {
-		// Make this a hard compile-time error.
-		::core::compile_error!("Prefer using the special `PmString` rule with the `string` keyword.");
-		::core::compile_error!(concat!("Use instead: ", stringify!($vis), " static progmem string ", stringify!($name), " = ..."));
-
-		// Emit a dummy to suppress errors where `$name` is used
-		static $name : $crate::wrapper::ProgMem< $crate::string::LoadedString< $ty > > = todo!();
+		// Crate the writable progmem page via internal macro
+		$crate::progmem_internal!{
+			$(#[$attr])* $vis static progmem page $name : [ u8 ; $size ] = $value;
+		}
 
 		// Recursive call to allow multiple items in macro invocation
 		$crate::progmem!{
@@ -744,19 +2672,17 @@ macro_rules! progmem {
 		}
 	};
 
-	// Catch references rule, reference are evil!
-	// (well actually they are not, but most likely using them *is* a mistake)
+	// Sorted rule
 	(
 		$( #[ $attr:meta ] )*
-		$vis:vis static progmem $name:ident : & $ty:ty = $value:expr ;
+		$vis:vis static progmem sorted $( < const $size_name:ident : usize > )? $name:ident : $ty:ty = $value:expr ;
 
 		$($rest:tt)*
 	) => {
-		// Make this a hard compile-time error
-		::core::compile_error!("Do not use a reference type for progmem, because this way only the reference itself would be in progmem, whereas the underlying data would still be in the normal data domain!");
-
-		// Emit a dummy to suppress errors where `$name` is used
-		static $name : & $ty = todo!();
+		// Crate the sorted progmem static via internal macro
+		$crate::progmem_internal!{
+			$(#[$attr])* $vis static progmem sorted $( < const $size_name : usize > )? $name : $ty = $value;
+		}
 
 		// Recursive call to allow multiple items in macro invocation
 		$crate::progmem!{
@@ -797,6 +2723,85 @@ pub const fn array_from_str<const N: usize>(s: &str) -> [u8; N] {
 }
 
 
+#[doc(hidden)]
+pub const fn strs_total_len(strs: &[&str]) -> usize {
+	let mut total = 0;
+	let mut idx = 0;
+	while idx < strs.len() {
+		total += strs[idx].len();
+		idx += 1;
+	}
+	total
+}
+
+#[doc(hidden)]
+pub const fn array_from_strs<const N: usize>(strs: &[&str]) -> [u8; N] {
+	let mut array = [0; N];
+	let mut pos = 0;
+	let mut idx = 0;
+	while idx < strs.len() {
+		let bytes = strs[idx].as_bytes();
+		let mut byte_idx = 0;
+		while byte_idx < bytes.len() {
+			array[pos] = bytes[byte_idx];
+			pos += 1;
+			byte_idx += 1;
+		}
+		idx += 1;
+	}
+	if pos != N {
+		panic!("Invalid array size");
+	}
+	array
+}
+
+#[doc(hidden)]
+pub const fn offsets_from_strs<const N: usize>(strs: &[&str]) -> [u16; N] {
+	if strs.len() + 1 != N {
+		panic!("Invalid array size");
+	}
+	let mut offsets = [0; N];
+	let mut pos = 0;
+	let mut idx = 0;
+	while idx < strs.len() {
+		pos += strs[idx].len();
+		if pos > u16::MAX as usize {
+			panic!("The string table is 64 KiB or larger");
+		}
+		offsets[idx + 1] = pos as u16;
+		idx += 1;
+	}
+	offsets
+}
+
+
+/// Only for internal use. Wraps the given progmem `static` in a `ProgMem`.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "elpm"))]
+macro_rules! progmem_wrap {
+	($static:path) => {
+		$crate::wrapper::ProgMem::new(::core::ptr::addr_of!($static))
+	};
+}
+
+/// Only for internal use. Wraps the given progmem `static` in a `ProgMem`,
+/// which loads it via `elpm` from its far address.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "elpm")]
+macro_rules! progmem_wrap {
+	($static:path) => {{
+		// The full address of the static can only be retrieved at runtime
+		fn addr() -> $crate::raw::FarAddr {
+			$crate::far_addr_of!($static)
+		}
+
+		$crate::wrapper::ProgMem::new_far(::core::ptr::addr_of!($static), addr)
+	}};
+}
+
+
 /// Only for internal use. Use the `progmem!` macro instead.
 #[doc(hidden)]
 #[macro_export]
@@ -831,9 +2836,7 @@ macro_rules! progmem_internal {
 				// SAFETY: This call is sound because we ensure with the above
 				// `link_section` attribute on `VALUE` that it is indeed
 				// in the progmem section.
-				$crate::wrapper::ProgMem::new(
-					::core::ptr::addr_of!(VALUE)
-				)
+				$crate::progmem_wrap!(VALUE)
 			};
 
 			// Just return the PmString wrapper around the local static
@@ -847,6 +2850,57 @@ macro_rules! progmem_internal {
 		};
 	};
 
+	// The string table rule creating the progmem strings via `PmStringTable`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem strings $name:ident = [ $( $value:expr ),* ] ;
+	} => {
+		// User attributes
+		$(#[$attr])*
+		// The facade static definition, this only contains pointers and thus
+		// is NOT in progmem.
+		$vis static $name: $crate::string::PmStringTable = {
+			// This bit runs at compile-time
+			const STRINGS: &[&str] = &[ $( $value ),* ];
+
+			// This inner hidden static contains all the strings concatenated.
+			//
+			// SAFETY: it must be stored in the progmem or text section!
+			// The `link_section` lets us define that:
+			#[cfg_attr(target_arch = "avr", link_section = ".progmem.data")]
+			static BLOB: [u8; $crate::wrapper::strs_total_len(STRINGS)] =
+				$crate::wrapper::array_from_strs(STRINGS);
+
+			// This inner hidden static contains the start offsets of the
+			// strings in `BLOB`, followed by the total length.
+			//
+			// SAFETY: it must be stored in the progmem or text section!
+			#[cfg_attr(target_arch = "avr", link_section = ".progmem.data")]
+			static OFFSETS: [u16; STRINGS.len() + 1] =
+				$crate::wrapper::offsets_from_strs(STRINGS);
+
+			let (blob, offsets) = unsafe {
+				// SAFETY: This call is sound because we ensure with the above
+				// `link_section` attributes that both are indeed in the
+				// progmem section.
+				(
+					$crate::progmem_wrap!(BLOB),
+					$crate::progmem_wrap!(OFFSETS),
+				)
+			};
+
+			unsafe {
+				// SAFETY: This call is sound, because we started out with
+				// `&str`s thus each string between two offsets in `BLOB`
+				// must be valid UTF-8
+				$crate::string::PmStringTable::new(
+					blob.as_slice(),
+					offsets.as_slice(),
+				)
+			}
+		};
+	};
+
 	// The rule creating an auto-sized progmem static via `ProgMem`
 	{
 		$( #[ $attr:meta ] )*
@@ -868,6 +2922,175 @@ macro_rules! progmem_internal {
 		}
 	};
 
+	// The far string rule creating the far progmem string static via
+	// `PmFarString`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem far string $name:ident = $value:expr ;
+	} => {
+		// User attributes
+		$(#[$attr])*
+		// The facade static definition, this only contains a function pointer
+		// and thus is NOT in progmem.
+		$vis static $name: $crate::string::PmFarString<{
+			// This bit runs at compile-time
+			let s: &str = $value;
+			s.len()
+		}> = {
+			// This inner hidden static contains the actual real raw value.
+			//
+			// SAFETY: it must be stored in the progmem or text section!
+			// The `.progmemx.data` section may be placed anywhere in the
+			// program memory, including beyond the 64 KiB limit.
+			#[cfg_attr(target_arch = "avr", link_section = ".progmemx.data")]
+			static VALUE: [u8; {
+				// This bit runs at compile-time
+				let s: &str = $value;
+				s.len()
+			}] = $crate::wrapper::array_from_str( $value );
+
+			// The full address of `VALUE` can only be retrieved at runtime
+			fn addr() -> $crate::raw::FarAddr {
+				$crate::far_addr_of!(VALUE)
+			}
+
+			let pm = unsafe {
+				// SAFETY: This call is sound because we ensure with the above
+				// `link_section` attribute on `VALUE` that it is indeed
+				// in the progmem section, and `addr` returns its address.
+				$crate::wrapper::ProgMemFar::new(addr)
+			};
+
+			// Just return the PmFarString wrapper around the local static
+			unsafe {
+				// SAFETY: This call is sound, because we started out with a
+				// `&str` thus the conent of `VALUE` must be valid UTF-8
+				$crate::string::PmFarString::new(
+					pm
+				)
+			}
+		};
+	};
+
+	// The rule creating an auto-sized far progmem static via `ProgMemFar`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem far < const $size_name:ident : usize > $name:ident : $ty:ty = $value:expr ;
+	} => {
+		// Create a constant with the size of the value, same as above.
+		$vis const $size_name : usize = {
+			$value.len()
+		};
+
+		// Just a normal far prgomem static, `$ty` may use the above constant
+		$crate::progmem_internal!{
+			$( #[ $attr ] )*
+			$vis static progmem far $name : $ty = $value ;
+		}
+	};
+
+	// The rule creating a far progmem static via `ProgMemFar`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem far $name:ident : $ty:ty = $value:expr ;
+	} => {
+		// User attributes
+		$(#[$attr])*
+		// The facade static definition, this only contains a function pointer
+		// and thus is NOT in progmem.
+		$vis static $name: $crate::wrapper::ProgMemFar<$ty> = {
+			// This inner hidden static contains the actual real raw value.
+			//
+			// SAFETY: it must be stored in the progmem or text section!
+			// The `.progmemx.data` section may be placed anywhere in the
+			// program memory, including beyond the 64 KiB limit.
+			#[cfg_attr(target_arch = "avr", link_section = ".progmemx.data")]
+			static VALUE: $ty = $value;
+
+			// The full address of `VALUE` can only be retrieved at runtime
+			fn addr() -> $crate::raw::FarAddr {
+				$crate::far_addr_of!(VALUE)
+			}
+
+			unsafe {
+				// SAFETY: This call is sound because we ensure with the above
+				// `link_section` attribute on `VALUE` that it is indeed
+				// in the progmem section, and `addr` returns its address.
+				$crate::wrapper::ProgMemFar::new(addr)
+			}
+		};
+	};
+
+	// The rule creating a writable progmem page via `ProgMemPage`
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem page $name:ident : [ u8 ; $size:literal ] = $value:expr ;
+	} => {
+		// User attributes
+		$(#[$attr])*
+		// The facade static definition, this only contains a pointer and thus
+		// is NOT in progmem.
+		$vis static $name: $crate::flash_write::ProgMemPage<$size> = {
+			// The page must be aligned to its size
+			#[repr(C, align($size))]
+			struct Page(::core::cell::UnsafeCell<[u8; $size]>);
+
+			unsafe impl Sync for Page {
+				// SAFETY: the page is only ever accessed via `ProgMemPage`
+			}
+
+			// This inner hidden static contains the actual real raw value.
+			//
+			// SAFETY: it must be stored in the progmem or text section!
+			// Since the page is writable, it gets its own section, which
+			// is still placed in the program memory by the linker.
+			#[cfg_attr(target_arch = "avr", link_section = ".progmem.page")]
+			static VALUE: Page = Page(::core::cell::UnsafeCell::new($value));
+
+			unsafe {
+				// SAFETY: This call is sound because we ensure with the above
+				// `link_section` attribute on `VALUE` that it is indeed
+				// in the progmem section, and aligned to the page size.
+				$crate::flash_write::ProgMemPage::new(
+					VALUE.0.get()
+				)
+			}
+		};
+	};
+
+	// The rule creating a progmem static via `ProgMem`, which is asserted at
+	// compile-time to be sorted
+	{
+		$( #[ $attr:meta ] )*
+		$vis:vis static progmem sorted $( < const $size_name:ident : usize > )? $name:ident : $ty:ty = $value:expr ;
+	} => {
+		// Just a normal progmem static
+		$crate::progmem_internal!{
+			$( #[ $attr ] )*
+			$vis static progmem $( < const $size_name : usize > )? $name : $ty = $value ;
+		}
+
+		// This bit runs at compile-time, it evaluates the value once more
+		// to check that its elements are in ascending order.
+		// Notice, this only works for types that can be compared in a
+		// `const` context, i.e. primitive types such as integers.
+		const _: () = {
+			let table: $ty = $value;
+
+			let mut idx = 1;
+			while idx < table.len() {
+				if table[idx - 1] > table[idx] {
+					::core::panic!(::core::concat!(
+						"The progmem table `",
+						::core::stringify!($name),
+						"` is not sorted"
+					));
+				}
+				idx += 1;
+			}
+		};
+	};
+
 	// The normal rule creating a progmem static via `ProgMem`
 	{
 		$( #[ $attr:meta ] )*
@@ -890,15 +3113,142 @@ macro_rules! progmem_internal {
 				// SAFETY: This call is sound because we ensure with the above
 				// `link_section` attribute on `VALUE` that it is indeed
 				// in the progmem section.
-				$crate::wrapper::ProgMem::new(
-					::core::ptr::addr_of!(VALUE)
-				)
+				$crate::progmem_wrap!(VALUE)
 			}
 		};
 	};
 }
 
 
+/// Get a wrapper of a field of a value in progmem, without loading it.
+///
+/// Given a `ProgMem<T>` (e.g. a static created by the [`progmem`] macro) and
+/// the name of a field of `T`, this macro returns a `ProgMem` of that field,
+/// which then can be [`load`](ProgMem::load)ed, without loading the entire
+/// `T`.
+/// The address of the field is computed via `addr_of!` on the raw pointer,
+/// thus it never creates a reference into the progmem.
+///
+/// Nested fields and fields of tuples are also supported (e.g.
+/// `pm_field!(FOO, bar.0)`), as well as elements of arrays via
+/// [`ProgMem::at`].
+///
+/// Notice, the field must be a field of `T` itself, accessing a field via
+/// `Deref` would create a reference into the progmem.
+/// Therefore, `T` (as well as the type of each nested field, that is
+/// accessed) must not implement `Deref`, otherwise this macro fails to
+/// compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use avr_progmem::progmem;
+/// use avr_progmem::pm_field;
+/// use avr_progmem::wrapper::ProgMem;
+///
+/// #[derive(Copy, Clone)]
+/// struct Record {
+///     id: u8,
+///     name: [u8; 31],
+///     range: (u32, u32),
+/// }
+///
+/// progmem!{
+///     static progmem RECORD: Record = Record {
+///         id: 42,
+///         name: [b'a'; 31],
+///         range: (1, 1000),
+///     };
+///
+///     static progmem TABLE: [Record; 2] = [
+///         Record { id: 1, name: [b'x'; 31], range: (0, 10) },
+///         Record { id: 2, name: [b'y'; 31], range: (10, 20) },
+///     ];
+/// }
+///
+/// // Only loads the single byte of `id`
+/// let id: ProgMem<u8> = pm_field!(RECORD, id);
+/// assert_eq!(42, id.load());
+///
+/// // Nested fields
+/// assert_eq!(1000, pm_field!(RECORD, range.1).load());
+///
+/// // Fields of elements of arrays
+/// assert_eq!(2, pm_field!(TABLE.at(1), id).load());
+/// assert_eq!(b'x', pm_field!(TABLE.at(0), name).load_at(3));
+/// ```
+#[macro_export]
+macro_rules! pm_field {
+	( $pm:expr , $( $field:tt ).+ ) => {
+		match $pm {
+			pm => {
+				// Project one field at a time, so each step can be checked
+				$(
+					let pm = unsafe {
+						// SAFETY: the closure just computes the address of the
+						// field, without dereferencing the pointer, and the
+						// check rejects any type that might be dereferenced
+						// via `Deref` to access the field.
+						$crate::wrapper::ProgMem::project(&pm, |ptr| {
+							#[allow(unused_imports)]
+							use $crate::wrapper::{DerefFieldCheck as _, DirectFieldCheck as _};
+
+							let _: $crate::wrapper::DirectField =
+								(&$crate::wrapper::FieldCheck::new(ptr)).check();
+
+							::core::ptr::addr_of!((*ptr).$field)
+						})
+					};
+				)+
+				pm
+			},
+		}
+	};
+}
+
+/// Helper of the [`pm_field`] macro to reject fields accessed via `Deref`.
+///
+/// The macro calls `check` on a `&FieldCheck<T>`, which resolves (by
+/// auto-ref) to [`DerefFieldCheck::check`] if `T` implements `Deref`, and
+/// to [`DirectFieldCheck::check`] otherwise. Only the latter returns a
+/// [`DirectField`], as expected by the macro.
+#[doc(hidden)]
+pub struct FieldCheck<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: ?Sized> FieldCheck<T> {
+	#[doc(hidden)]
+	pub fn new(_ptr: *const T) -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// The result of the [`FieldCheck`] of types not implementing `Deref`.
+#[doc(hidden)]
+pub struct DirectField;
+
+/// The result of the [`FieldCheck`] of types implementing `Deref`.
+#[doc(hidden)]
+pub struct FieldOfDerefType;
+
+#[doc(hidden)]
+pub trait DerefFieldCheck {
+	fn check(&self) -> FieldOfDerefType {
+		FieldOfDerefType
+	}
+}
+
+impl<T: ?Sized + Deref> DerefFieldCheck for FieldCheck<T> {}
+
+#[doc(hidden)]
+pub trait DirectFieldCheck {
+	fn check(&self) -> DirectField {
+		DirectField
+	}
+}
+
+impl<T: ?Sized> DirectFieldCheck for &FieldCheck<T> {}
+
+
 /// ```compile_fail
 /// use avr_progmem::progmem;
 /// progmem! {
@@ -909,6 +3259,40 @@ macro_rules! progmem_internal {
 pub struct ProgMemReferenceTest;
 
 
+/// ```compile_fail
+/// use avr_progmem::pm_field;
+/// use avr_progmem::progmem;
+/// use core::ops::Deref;
+///
+/// struct Inner {
+/// 	value: u8,
+/// }
+///
+/// struct Outer {
+/// 	inner: Inner,
+/// }
+///
+/// impl Deref for Outer {
+/// 	type Target = Inner;
+///
+/// 	fn deref(&self) -> &Inner {
+/// 		&self.inner
+/// 	}
+/// }
+///
+/// progmem! {
+/// 	static progmem OUTER: Outer = Outer { inner: Inner { value: 42 } };
+/// }
+///
+/// // Should fail to compile, because `value` is only a field of `Inner`,
+/// // thus it would be accessed via `Deref`, creating a reference into
+/// // the progmem
+/// let _ = pm_field!(OUTER, value);
+/// ```
+#[cfg(doctest)]
+pub struct PmFieldDerefTest;
+
+
 /// ```compile_fail
 /// use avr_progmem::progmem;
 /// progmem! {
@@ -919,3 +3303,14 @@ pub struct ProgMemReferenceTest;
 /// ```
 #[cfg(doctest)]
 pub struct HandStringTest;
+
+
+/// ```compile_fail
+/// use avr_progmem::progmem;
+/// progmem! {
+/// 	// Should fail to compile, because the table is not sorted
+/// 	static progmem sorted UNSORTED: [u8; 3] = [1, 3, 2];
+/// }
+/// ```
+#[cfg(doctest)]
+pub struct UnsortedTableTest;
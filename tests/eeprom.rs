@@ -0,0 +1,62 @@
+//! Host tests of the simulated EEPROM.
+
+use avr_progmem::eeprom;
+
+
+eeprom! {
+	static eeprom VALUE: u32 = 0x1234_5678;
+
+	static eeprom ARRAY: [u16; 4] = [1, 2, 3, 4];
+
+	static eeprom SHARED: [u8; 64] = [0; 64];
+}
+
+
+#[test]
+fn store_load_round_trip() {
+	assert_eq!(0x1234_5678, VALUE.load());
+	VALUE.store(42);
+	assert_eq!(42, VALUE.load());
+	VALUE.store(u32::MAX);
+	assert_eq!(u32::MAX, VALUE.load());
+}
+
+#[test]
+fn array_round_trip() {
+	assert_eq!(4, ARRAY.len());
+	assert!(!ARRAY.is_empty());
+	assert_eq!(4, ARRAY.as_slice().len());
+
+	ARRAY.store_at(2, 42);
+	assert_eq!(42, ARRAY.load_at(2));
+	assert_eq!([1, 2, 42, 4], ARRAY.load());
+
+	ARRAY.as_slice().store_at(0, 7);
+	assert_eq!(7, ARRAY.as_slice().load_at(0));
+	assert_eq!(vec![7, 2, 42, 4], ARRAY.iter().collect::<Vec<_>>());
+
+	ARRAY.store([5, 6, 7, 8]);
+	assert_eq!([5, 6, 7, 8], ARRAY.load());
+}
+
+#[test]
+fn concurrent_stores_are_not_torn() {
+	let threads: Vec<_> = (0..4u8)
+		.map(|t| {
+			std::thread::spawn(move || {
+				for _ in 0..1000 {
+					// Each thread stores the array filled with its own byte
+					SHARED.store([t; 64]);
+
+					// Whatever thread stored last, it stored the entire array
+					let loaded = SHARED.load();
+					assert!(loaded.iter().all(|&b| b == loaded[0]), "{:?}", loaded);
+				}
+			})
+		})
+		.collect();
+
+	for t in threads {
+		t.join().unwrap();
+	}
+}
@@ -0,0 +1,120 @@
+//! Host tests of the far progmem wrappers.
+
+use core::cmp::Ordering;
+
+use avr_progmem::progmem;
+
+
+progmem! {
+	static progmem far NUMBERS: [u16; 6] = [1, 2, 3, 4, 5, 6];
+
+	static progmem far EMPTY: [u32; 0] = [];
+
+	static progmem far string TEXT = "dai 大賢者 kenja";
+
+	static progmem far string NOTHING = "";
+
+	/// A normal static, which is loaded via `elpm` with the "elpm" crate
+	/// feature, in chunks for comparing and searching
+	static progmem <const TEXT_LEN: usize> LONG_TEXT: [u8; TEXT_LEN] =
+		*include_bytes!("../examples/test_text.txt");
+}
+
+
+#[test]
+fn slices() {
+	let all = NUMBERS.as_slice();
+	assert_eq!(6, all.len());
+	assert_eq!(4, all.load_at(3));
+
+	let mid = all.sub_slice(1..5);
+	assert_eq!(4, mid.len());
+	assert_eq!(2, mid.load_at(0));
+	assert_eq!(5, mid.at(3).load());
+
+	// Sub-slices of sub-slices keep their offset
+	let inner = mid.sub_slice(2..4);
+	assert_eq!(vec![4, 5], inner.iter().collect::<Vec<_>>());
+
+	let none = mid.sub_slice(4..4);
+	assert!(none.is_empty());
+	assert_eq!(None, none.iter().next());
+
+	assert!(EMPTY.is_empty());
+	assert!(EMPTY.as_slice().is_empty());
+	assert_eq!(0, EMPTY.iter().count());
+}
+
+#[test]
+#[should_panic]
+fn sub_slice_out_of_bounds() {
+	NUMBERS.as_slice().sub_slice(2..4).sub_slice(1..3);
+}
+
+#[test]
+fn iterators() {
+	assert_eq!(vec![1, 2, 3, 4, 5, 6], NUMBERS.iter().collect::<Vec<_>>());
+	assert_eq!(21, (&NUMBERS).into_iter().sum::<u16>());
+
+	let mut iter = NUMBERS.iter();
+	assert_eq!(6, iter.len());
+	assert_eq!(Some(6), iter.next_back());
+	assert_eq!(Some(2), iter.nth(1));
+	assert_eq!(Some(4), iter.nth_back(1));
+	assert_eq!(1, iter.len());
+	assert_eq!(Some(3), iter.last());
+
+	let mut iter = NUMBERS.iter();
+	assert_eq!(None, iter.nth(6));
+	assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn strings() {
+	assert_eq!(19, TEXT.len());
+	assert_eq!("dai 大賢者 kenja", &*TEXT.load());
+	assert_eq!("dai 大賢者 kenja", format!("{}", TEXT));
+
+	let chars: Vec<char> = TEXT.chars().collect();
+	assert_eq!("dai 大賢者 kenja".chars().collect::<Vec<_>>(), chars);
+	let rev: String = TEXT.chars().rev().collect();
+	assert_eq!("dai 大賢者 kenja".chars().rev().collect::<String>(), rev);
+
+	assert!(NOTHING.is_empty());
+	assert_eq!("", format!("{}", NOTHING));
+	assert_eq!(None, NOTHING.chars().next_back());
+}
+
+#[test]
+fn chunked_near_statics() {
+	let text: &[u8] = include_bytes!("../examples/test_text.txt");
+
+	assert!(LONG_TEXT.eq_slice(text));
+	assert_eq!(Ordering::Equal, LONG_TEXT.cmp_slice(text));
+	assert_eq!(Ordering::Less, LONG_TEXT.cmp_slice(&[text, b"!"].concat()));
+
+	// A mismatch in the very last chunk
+	let mut changed = text.to_vec();
+	*changed.last_mut().unwrap() += 1;
+	assert!(!LONG_TEXT.eq_slice(&changed));
+	assert_eq!(Ordering::Less, LONG_TEXT.cmp_slice(&changed));
+
+	let find = |needle: u8| text.iter().position(|&b| b == needle);
+	let rfind = |needle: u8| text.iter().rposition(|&b| b == needle);
+	assert_eq!(find(b'.'), LONG_TEXT.find_byte(b'.'));
+	assert_eq!(rfind(b'.'), LONG_TEXT.rfind_byte(b'.'));
+	assert_eq!(rfind(b'#'), LONG_TEXT.rfind_byte(b'#'));
+	assert_eq!(None, LONG_TEXT.find_byte(0));
+	assert_eq!(None, LONG_TEXT.rfind_byte(0));
+	assert_eq!(
+		text.iter().position(|&b| b == b'z'),
+		LONG_TEXT.position(|b| b == b'z')
+	);
+
+	// Sub-slices keep their offset
+	let mid = LONG_TEXT.as_slice().sub_slice(40..1000);
+	assert!(mid.eq_slice(&text[40..1000]));
+	let mid_rfind = text[40..1000].iter().rposition(|&b| b == b'\n');
+	assert_eq!(mid_rfind, mid.rfind_byte(b'\n'));
+	assert_eq!(text[500], mid.load_at(460));
+}
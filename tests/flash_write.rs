@@ -0,0 +1,58 @@
+//! Host tests of the simulated page programming.
+
+use avr_progmem::progmem;
+
+
+progmem! {
+	static progmem page SINGLE: [u8; 128] = [0xFF; 128];
+
+	static progmem page PAGE_A: [u8; 64] = [0xFF; 64];
+	static progmem page PAGE_B: [u8; 64] = [0xFF; 64];
+	static progmem page PAGE_C: [u8; 64] = [0xFF; 64];
+	static progmem page PAGE_D: [u8; 64] = [0xFF; 64];
+}
+
+
+#[test]
+fn write_erase_round_trip() {
+	let mut data = [0; 128];
+	data[..5].copy_from_slice(b"Hello");
+
+	// SAFETY: this page is only used by this test
+	unsafe { SINGLE.write(&data) };
+	assert_eq!(data, SINGLE.load());
+
+	// Writing again erases the page first
+	data[..5].copy_from_slice(b"World");
+	unsafe { SINGLE.write(&data) };
+	assert_eq!(data, SINGLE.load());
+
+	unsafe { SINGLE.erase() };
+	assert_eq!([0xFF; 128], SINGLE.load());
+}
+
+#[test]
+fn concurrent_writes_of_distinct_pages() {
+	let pages = [PAGE_A, PAGE_B, PAGE_C, PAGE_D];
+
+	let threads: Vec<_> = pages
+		.iter()
+		.copied()
+		.enumerate()
+		.map(|(t, page)| {
+			std::thread::spawn(move || {
+				for round in 0..200u8 {
+					let data = [t as u8 ^ round; 64];
+
+					// SAFETY: each page is only used by a single thread
+					unsafe { page.write(&data) };
+					assert_eq!(data, page.load());
+				}
+			})
+		})
+		.collect();
+
+	for t in threads {
+		t.join().unwrap();
+	}
+}
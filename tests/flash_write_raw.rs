@@ -0,0 +1,48 @@
+//! Host tests of the simulated raw page programming functions.
+
+use avr_progmem::flash_write;
+use avr_progmem::progmem;
+
+
+progmem! {
+	static progmem page RAW: [u8; 64] = [0xFF; 64];
+	static progmem page OTHER: [u8; 64] = [0xFF; 64];
+}
+
+
+// All uses of the raw functions are in this one test (and this test binary),
+// since they share the page buffer, which is not guarded against the
+// `ProgMemPage` methods
+#[test]
+fn raw_page_programming() {
+	let page: *const u8 = RAW.as_ptr().cast();
+
+	// SAFETY: this page is only used by this test
+	unsafe {
+		flash_write::erase_page::<64>(page);
+		flash_write::fill_page_buffer(page.wrapping_add(2), 0x3412);
+		flash_write::write_page::<64>(page);
+		flash_write::enable_rww();
+	}
+
+	let mut expected = [0xFF; 64];
+	expected[2..4].copy_from_slice(&[0x12, 0x34]);
+	assert_eq!(expected, RAW.load());
+
+	// Writing can only clear bits
+	unsafe {
+		flash_write::fill_page_buffer(page.wrapping_add(2), 0xFFF0);
+		flash_write::write_page::<64>(page);
+	}
+	expected[2] = 0x10;
+	assert_eq!(expected, RAW.load());
+
+	// Writing a page, while the buffer was filled for another page panics
+	let res = std::panic::catch_unwind(|| unsafe {
+		flash_write::fill_page_buffer(OTHER.as_ptr().cast(), 0);
+		flash_write::write_page::<64>(page);
+	});
+	assert!(res.is_err());
+	assert_eq!(expected, RAW.load());
+	assert_eq!([0xFF; 64], OTHER.load());
+}
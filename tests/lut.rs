@@ -0,0 +1,93 @@
+//! Host tests of the interpolating lookup tables.
+
+use avr_progmem::lut::PmLut;
+use avr_progmem::progmem;
+
+
+progmem! {
+	static progmem FALLING: [(u16, u16); 3] = [(0, 10), (3, 0), (5, 0)];
+
+	static progmem WIDE: [(u32, u16); 2] = [(0, 0), (200_000, 1000)];
+
+	static progmem FULL_RANGE: [(i32, i16); 2] = [(i32::MIN, 0), (i32::MAX, 1000)];
+
+	// The `y` needs neither `Ord` nor `Lerp` for `nearest`
+	static progmem LETTERS: [(u8, char); 3] = [(0, 'a'), (10, 'b'), (11, 'c')];
+
+	static progmem STEPS: [(u16, u16); 8] = [
+		(0, 0),
+		(10, 1),
+		(20, 2),
+		(30, 3),
+		(40, 4),
+		(50, 5),
+		(60, 6),
+		(70, 7),
+	];
+}
+
+
+#[test]
+fn linear_decreasing_unsigned() {
+	let lut = PmLut::new(FALLING);
+
+	assert_eq!(10, lut.linear(0));
+	// Rounded towards the lower breakpoint, i.e. up
+	assert_eq!(7, lut.linear(1));
+	assert_eq!(4, lut.linear(2));
+	assert_eq!(0, lut.linear(3));
+	assert_eq!(0, lut.linear(4));
+	assert_eq!(0, lut.linear(u16::MAX));
+}
+
+#[test]
+fn linear_wide_gaps() {
+	let lut = PmLut::new(WIDE);
+
+	assert_eq!(0, lut.linear(0));
+	assert_eq!(500, lut.linear(100_000));
+	assert_eq!(750, lut.linear(150_000));
+	assert_eq!(999, lut.linear(199_999));
+	assert_eq!(1000, lut.linear(200_000));
+	assert_eq!(1000, lut.linear(u32::MAX));
+
+	let lut = PmLut::new(FULL_RANGE);
+
+	assert_eq!(0, lut.linear(i32::MIN));
+	assert_eq!(500, lut.linear(0));
+	assert_eq!(1000, lut.linear(i32::MAX));
+}
+
+#[test]
+fn nearest_ties() {
+	let lut = PmLut::new(LETTERS);
+
+	assert_eq!('a', lut.nearest(4));
+	// Exactly in the middle, the lower one is taken
+	assert_eq!('a', lut.nearest(5));
+	assert_eq!('b', lut.nearest(6));
+	assert_eq!('b', lut.nearest(10));
+	assert_eq!('c', lut.nearest(11));
+	assert_eq!('c', lut.nearest(u8::MAX));
+}
+
+#[cfg(feature = "instrument")]
+#[test]
+fn loads_only_keys_while_searching() {
+	use avr_progmem::raw::instrument;
+
+	let lut = PmLut::new(STEPS);
+	assert!(instrument::watch("STEPS", &STEPS));
+
+	// Probes the `x` at 4, 2, and 1, then loads the `y` at 1 and 2
+	assert_eq!(1, lut.nearest(15));
+	let counters = instrument::counters(&STEPS).unwrap();
+	assert_eq!(5, counters.reads);
+	assert_eq!(10, counters.bytes);
+
+	// An exact hit only loads its `y` in addition
+	assert_eq!(4, lut.nearest(40));
+	let counters = instrument::counters(&STEPS).unwrap();
+	assert_eq!(7, counters.reads);
+	assert_eq!(14, counters.bytes);
+}
@@ -0,0 +1,84 @@
+//! Host tests of the progmem strings.
+
+use avr_progmem::string::validate_utf8;
+use avr_progmem::string::PmString;
+use avr_progmem::wrapper::ProgMem;
+
+
+/// Bytes covering all the classes of UTF-8 bytes: ASCII, continuation bytes
+/// (with the sub-ranges that are valid only after specific first bytes), and
+/// first bytes of each length, as well as bytes that never occur.
+const BYTES: [u8; 14] = [
+	b'a', 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0, 0xC2, 0xE0, 0xED, 0xF0, 0xF4,
+];
+
+/// Calls `f` with every sequence of four bytes out of `BYTES`.
+fn for_each_sequence(mut f: impl FnMut([u8; 4])) {
+	for &a in BYTES.iter() {
+		for &b in BYTES.iter() {
+			for &c in BYTES.iter() {
+				for &d in BYTES.iter() {
+					f([a, b, c, d]);
+				}
+			}
+		}
+	}
+}
+
+
+/// Checks `PmString::try_from_progmem` against `core::str::from_utf8`,
+/// returning whether the bytes are invalid.
+fn try_from_progmem_as_core<const N: usize>(seq: [u8; N]) -> bool {
+	let expected = core::str::from_utf8(&seq);
+	// SAFETY: on non-AVR, normal data memory is used as progmem, and the
+	// wrapper is only used while `seq` is alive
+	let actual = PmString::try_from_progmem(unsafe { ProgMem::new(&seq) });
+
+	match (expected, actual) {
+		(Ok(s), Ok(pm)) => {
+			assert_eq!(s, &*pm.load(), "{:x?}", seq);
+			false
+		},
+		(Err(e), Err(a)) => {
+			assert_eq!(e.valid_up_to(), a.valid_up_to(), "{:x?}", seq);
+			assert_eq!(e.error_len(), a.error_len(), "{:x?}", seq);
+			assert_eq!(e.to_string(), a.to_string(), "{:x?}", seq);
+			true
+		},
+		(e, a) => panic!("{:x?}: expected {:?}, got {:?}", seq, e, a.map(|_| ())),
+	}
+}
+
+#[test]
+fn try_from_progmem_matches_core() {
+	let mut total = 0;
+	let mut invalid = 0;
+
+	for_each_sequence(|[a, b, c, d]| {
+		total += 1;
+		if try_from_progmem_as_core([a, b, c, d]) {
+			invalid += 1;
+		}
+
+		// Also check all the prefixes, i.e. incomplete sequences
+		try_from_progmem_as_core([a, b, c]);
+		try_from_progmem_as_core([a, b]);
+		try_from_progmem_as_core([a]);
+	});
+
+	// Most of them should be invalid
+	assert!(invalid > total / 2);
+}
+
+#[test]
+fn validate_utf8_matches_core() {
+	for_each_sequence(|seq| {
+		// Also check all the prefixes, i.e. incomplete sequences
+		for len in 0..=seq.len() {
+			let bytes = &seq[..len];
+			let expected = core::str::from_utf8(bytes).map(|_| ()).map_err(Into::into);
+
+			assert_eq!(expected, validate_utf8(bytes), "{:x?}", bytes);
+		}
+	});
+}
@@ -0,0 +1,90 @@
+//! Demangling of Rust symbol names
+//!
+//! Only the legacy Rust mangling scheme (the default of `rustc`) is
+//! supported, any other symbol is returned as is.
+
+
+/// Demangle a legacy Rust symbol, e.g. `_ZN3foo3BAR17h0123456789abcdefE`
+/// becomes `foo::BAR`.
+///
+/// The trailing hash is dropped, and any symbol that is not a (valid) Rust
+/// symbol is returned unchanged.
+pub fn demangle(symbol: &str) -> String {
+	try_demangle(symbol).unwrap_or_else(|| symbol.to_string())
+}
+
+fn try_demangle(symbol: &str) -> Option<String> {
+	let mut rest = symbol
+		.strip_prefix("_ZN")
+		.or_else(|| symbol.strip_prefix("__ZN"))?;
+
+	let mut path: Vec<String> = Vec::new();
+
+	while !rest.starts_with('E') {
+		let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+		let len: usize = rest[..digits].parse().ok()?;
+		let ident = rest.get(digits..digits + len)?;
+		rest = &rest[digits + len..];
+
+		path.push(unescape(ident)?);
+	}
+
+	// Drop the hash, if any
+	if path.last().is_some_and(|last| is_hash(last)) {
+		path.pop();
+	}
+
+	if path.is_empty() {
+		None
+	} else {
+		Some(path.join("::"))
+	}
+}
+
+/// Returns whether the given path segment is the hash of a symbol.
+fn is_hash(ident: &str) -> bool {
+	ident.len() == 17 && ident.starts_with('h') && ident[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Decode the `$...$` escapes of a path segment.
+fn unescape(mut ident: &str) -> Option<String> {
+	let mut out = String::with_capacity(ident.len());
+
+	// A leading underscore is only added to escape a leading `$`
+	if ident.starts_with("_$") {
+		ident = &ident[1..];
+	}
+
+	while !ident.is_empty() {
+		if let Some(tail) = ident.strip_prefix('$') {
+			let end = tail.find('$')?;
+			let escape = &tail[..end];
+			ident = &tail[end + 1..];
+
+			let c = match escape {
+				"SP" => '@',
+				"BP" => '*',
+				"RF" => '&',
+				"LT" => '<',
+				"GT" => '>',
+				"LP" => '(',
+				"RP" => ')',
+				"C" => ',',
+				_ => {
+					let hex = escape.strip_prefix('u')?;
+					std::char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+				},
+			};
+			out.push(c);
+		} else if let Some(tail) = ident.strip_prefix("..") {
+			out.push_str("::");
+			ident = tail;
+		} else {
+			let c = ident.chars().next()?;
+			out.push(c);
+			ident = &ident[c.len_utf8()..];
+		}
+	}
+
+	Some(out)
+}
@@ -0,0 +1,265 @@
+//! A minimal parser for 32-bit little-endian ELF files
+//!
+//! This parser only reads what is needed to list the symbols of an AVR
+//! firmware, i.e. the file header, the section headers, and the symbol table.
+
+
+use std::convert::TryInto;
+use std::fmt;
+
+
+/// The ELF machine number of AVR.
+pub const EM_AVR: u16 = 83;
+
+/// Section type of a symbol table.
+const SHT_SYMTAB: u32 = 2;
+/// Section type of a section without file data (e.g. `.bss`).
+pub const SHT_NOBITS: u32 = 8;
+
+/// Section flag of sections occupying memory at runtime.
+pub const SHF_ALLOC: u32 = 0x2;
+
+/// Symbol type of data objects (e.g. statics).
+const STT_OBJECT: u8 = 1;
+/// Symbol type of functions.
+const STT_FUNC: u8 = 2;
+
+/// The first reserved section index (e.g. of absolute symbols).
+const SHN_LORESERVE: u16 = 0xff00;
+
+/// The size of a section header entry.
+const SECTION_HEADER_SIZE: usize = 40;
+/// The size of a symbol table entry.
+const SYMBOL_SIZE: usize = 16;
+
+
+/// An error while parsing an ELF file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	/// The file does not start with the ELF magic.
+	NotElf,
+	/// The file is not a 32-bit little-endian ELF, as used by AVR.
+	UnsupportedFormat,
+	/// The file is not for the AVR architecture, contains the actual machine.
+	NotAvr(u16),
+	/// The file has no symbol table (e.g. it was stripped).
+	NoSymbols,
+	/// Some structure points outside of the file.
+	Truncated(&'static str),
+	/// Some name is not valid UTF-8 or not terminated.
+	BadString,
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::NotElf => write!(fmt, "not an ELF file"),
+			Error::UnsupportedFormat => write!(fmt, "not a 32-bit little-endian ELF file"),
+			Error::NotAvr(machine) => {
+				write!(fmt, "not an AVR ELF file (machine {})", machine)
+			},
+			Error::NoSymbols => write!(fmt, "no symbol table, is the file stripped?"),
+			Error::Truncated(what) => write!(fmt, "truncated file, {} out of bounds", what),
+			Error::BadString => write!(fmt, "invalid string table entry"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+
+/// The type of an ELF file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+	/// An object file (`.o`), all addresses are relative to their section.
+	Relocatable,
+	/// A linked firmware, all addresses are final.
+	Executable,
+	/// Anything else (e.g. a core dump), contains the `e_type`.
+	Other(u16),
+}
+
+/// The type of a symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+	/// A data object, such as a `static`.
+	Object,
+	/// A function.
+	Func,
+	/// Anything else, e.g. a section or file symbol.
+	Other,
+}
+
+/// A section header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+	/// The section name, e.g. `.text`.
+	pub name: &'a str,
+	/// The section type (`sh_type`).
+	pub kind: u32,
+	/// The section flags (`sh_flags`).
+	pub flags: u32,
+	/// The address of the section (zero in object files).
+	pub addr: u32,
+	/// The size of the section in bytes.
+	pub size: u32,
+}
+
+/// An entry of the symbol table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol<'a> {
+	/// The (mangled) symbol name.
+	pub name: &'a str,
+	/// The address of the symbol (relative to its section in object files).
+	pub value: u32,
+	/// The size of the symbol in bytes.
+	pub size: u32,
+	/// The type of the symbol.
+	pub kind: SymbolKind,
+	/// The index of the section of the symbol, if any.
+	pub section: Option<usize>,
+}
+
+/// A parsed ELF file.
+#[derive(Debug, Clone)]
+pub struct Elf<'a> {
+	/// The type of the file.
+	pub kind: FileKind,
+	/// All the sections, indexed by their section index.
+	pub sections: Vec<Section<'a>>,
+	/// All the symbols of the symbol table.
+	pub symbols: Vec<Symbol<'a>>,
+}
+
+impl<'a> Elf<'a> {
+	/// Parse an AVR ELF file.
+	pub fn parse(data: &'a [u8]) -> Result<Self, Error> {
+		if data.len() < 52 || &data[..4] != b"\x7fELF" {
+			return Err(Error::NotElf);
+		}
+		// Only ELFCLASS32 and ELFDATA2LSB are used by AVR
+		if data[4] != 1 || data[5] != 1 {
+			return Err(Error::UnsupportedFormat);
+		}
+
+		let machine = u16_at(data, 18, "header")?;
+		if machine != EM_AVR {
+			return Err(Error::NotAvr(machine));
+		}
+
+		let kind = match u16_at(data, 16, "header")? {
+			1 => FileKind::Relocatable,
+			2 => FileKind::Executable,
+			other => FileKind::Other(other),
+		};
+
+		let shoff = u32_at(data, 32, "header")? as usize;
+		let shnum = u16_at(data, 48, "header")? as usize;
+		let shstrndx = u16_at(data, 50, "header")? as usize;
+
+		// The raw section headers: (name offset, header offset)
+		let headers = (0..shnum)
+			.map(|i| shoff + i * SECTION_HEADER_SIZE)
+			.map(|off| Ok((u32_at(data, off, "section header")?, off)))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let shstrtab = match headers.get(shstrndx) {
+			Some(&(_, off)) => section_data(data, off)?,
+			None => &[],
+		};
+
+		let sections = headers
+			.iter()
+			.map(|&(name, off)| {
+				Ok(Section {
+					name: str_at(shstrtab, name)?,
+					kind: u32_at(data, off + 4, "section header")?,
+					flags: u32_at(data, off + 8, "section header")?,
+					addr: u32_at(data, off + 12, "section header")?,
+					size: u32_at(data, off + 20, "section header")?,
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let symtab_idx = sections
+			.iter()
+			.position(|s| s.kind == SHT_SYMTAB)
+			.ok_or(Error::NoSymbols)?;
+		let symtab_off = headers[symtab_idx].1;
+		let symtab = section_data(data, symtab_off)?;
+
+		// The string table of the symbols is given by the `sh_link`
+		let strtab_idx = u32_at(data, symtab_off + 24, "section header")? as usize;
+		let strtab = match headers.get(strtab_idx) {
+			Some(&(_, off)) => section_data(data, off)?,
+			None => return Err(Error::Truncated("symbol string table")),
+		};
+
+		let symbols = symtab
+			.chunks_exact(SYMBOL_SIZE)
+			.map(|sym| {
+				let info = sym[12];
+				let shndx = u16_at(sym, 14, "symbol")?;
+
+				Ok(Symbol {
+					name: str_at(strtab, u32_at(sym, 0, "symbol")?)?,
+					value: u32_at(sym, 4, "symbol")?,
+					size: u32_at(sym, 8, "symbol")?,
+					kind: match info & 0xf {
+						STT_OBJECT => SymbolKind::Object,
+						STT_FUNC => SymbolKind::Func,
+						_ => SymbolKind::Other,
+					},
+					section: if shndx == 0 || shndx >= SHN_LORESERVE {
+						None
+					} else {
+						Some(shndx as usize)
+					},
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		Ok(Elf {
+			kind,
+			sections,
+			symbols,
+		})
+	}
+
+	/// Returns the section of the given symbol, if any.
+	pub fn section_of(&self, symbol: &Symbol) -> Option<&Section<'a>> {
+		symbol.section.and_then(|idx| self.sections.get(idx))
+	}
+}
+
+
+/// Returns the file data of the section with the header at `off`.
+fn section_data(data: &[u8], off: usize) -> Result<&[u8], Error> {
+	let start = u32_at(data, off + 16, "section header")? as usize;
+	let size = u32_at(data, off + 20, "section header")? as usize;
+
+	data.get(start..start + size)
+		.ok_or(Error::Truncated("section data"))
+}
+
+/// Read the little-endian `u16` at `off`.
+fn u16_at(data: &[u8], off: usize, what: &'static str) -> Result<u16, Error> {
+	data.get(off..off + 2)
+		.map(|b| u16::from_le_bytes(b.try_into().unwrap()))
+		.ok_or(Error::Truncated(what))
+}
+
+/// Read the little-endian `u32` at `off`.
+fn u32_at(data: &[u8], off: usize, what: &'static str) -> Result<u32, Error> {
+	data.get(off..off + 4)
+		.map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+		.ok_or(Error::Truncated(what))
+}
+
+/// Read the null-terminated string at `off` of the given string table.
+fn str_at(strtab: &[u8], off: u32) -> Result<&str, Error> {
+	let tail = strtab.get(off as usize..).ok_or(Error::BadString)?;
+	let len = tail.iter().position(|&b| b == 0).ok_or(Error::BadString)?;
+
+	std::str::from_utf8(&tail[..len]).map_err(|_| Error::BadString)
+}
@@ -0,0 +1,15 @@
+//!
+//! Reports the progmem usage per static of an AVR firmware.
+//!
+//! This is the library part of the `avr-progmem-size` tool, which parses an
+//! AVR firmware ELF (or object file) and lists all the statics that are
+//! stored in progmem (e.g. via the `progmem!` macro of the `avr-progmem`
+//! crate) with their address and size.
+//! Additionally, it flags progmem statics which ended up above 64 KiB, where
+//! they can not be read via `lpm`, and large initialized statics in RAM,
+//! which look like they should have been put into progmem instead.
+//!
+
+pub mod demangle;
+pub mod elf;
+pub mod report;
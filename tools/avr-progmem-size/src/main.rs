@@ -0,0 +1,81 @@
+//!
+//! `avr-progmem-size`, reports the progmem usage per static of an AVR
+//! firmware ELF.
+//!
+//! Usage: `avr-progmem-size [--min-ram-size BYTES] FIRMWARE.elf`
+//!
+//! The exit code is `1`, if any progmem static ended up above 64 KiB, and
+//! `2` on any other error.
+//!
+
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+
+use avr_progmem_size::elf::Elf;
+use avr_progmem_size::report::Report;
+use avr_progmem_size::report::DEFAULT_MIN_RAM_SIZE;
+
+
+const USAGE: &str = "Usage: avr-progmem-size [--min-ram-size BYTES] FIRMWARE.elf
+
+Lists all progmem statics of the given AVR firmware ELF (or object file),
+flags progmem statics above 64 KiB, and initialized statics in RAM of at
+least BYTES (default 16) bytes.";
+
+
+fn main() {
+	let mut min_ram_size = DEFAULT_MIN_RAM_SIZE;
+	let mut file = None;
+
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"-h" | "--help" => {
+				println!("{}", USAGE);
+				return;
+			},
+			"--min-ram-size" => {
+				min_ram_size = match args.next().and_then(|s| s.parse().ok()) {
+					Some(size) => size,
+					None => fail("--min-ram-size requires a number of bytes"),
+				};
+			},
+			_ if file.is_none() && !arg.starts_with('-') => file = Some(arg),
+			_ => fail(&format!("unexpected argument: {}", arg)),
+		}
+	}
+
+	let file = match file {
+		Some(file) => file,
+		None => fail("no firmware file given"),
+	};
+
+	let data = match fs::read(&file) {
+		Ok(data) => data,
+		Err(err) => fail(&format!("can not read {}: {}", file, err)),
+	};
+
+	let elf = match Elf::parse(&data) {
+		Ok(elf) => elf,
+		Err(err) => fail(&format!("can not parse {}: {}", file, err)),
+	};
+
+	let report = Report::new(&elf);
+
+	let stdout = io::stdout();
+	if let Err(err) = report.print(&mut stdout.lock(), min_ram_size) {
+		fail(&format!("can not write the report: {}", err));
+	}
+
+	if report.beyond_near_limit().next().is_some() {
+		process::exit(1);
+	}
+}
+
+/// Print the error with the usage and exit.
+fn fail(msg: &str) -> ! {
+	eprintln!("error: {}\n\n{}", msg, USAGE);
+	process::exit(2);
+}
@@ -0,0 +1,250 @@
+//! Classification of the statics of an AVR firmware
+
+
+use std::io;
+use std::io::Write;
+
+use crate::demangle::demangle;
+use crate::elf::Elf;
+use crate::elf::FileKind;
+use crate::elf::SymbolKind;
+use crate::elf::SHF_ALLOC;
+use crate::elf::SHT_NOBITS;
+
+
+/// The offset at which `avr-ld` places the data memory (RAM).
+///
+/// Every address below this offset is in the program memory.
+pub const DATA_OFFSET: u32 = 0x80_0000;
+
+/// The offset at which `avr-ld` places the EEPROM.
+pub const EEPROM_OFFSET: u32 = 0x81_0000;
+
+/// The part of the program memory, which is reachable via the `lpm`
+/// instruction and thus via `avr_progmem::raw::read_byte`.
+pub const NEAR_LIMIT: u32 = 0x1_0000;
+
+/// The default minimum size of RAM data to report.
+pub const DEFAULT_MIN_RAM_SIZE: u32 = 16;
+
+
+/// Where a static is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+	/// In progmem, read via `lpm` (i.e. in `.progmem.data`).
+	Progmem,
+	/// In the far progmem, read via `elpm` (i.e. in `.progmemx.data`).
+	ProgmemFar,
+	/// Initialized data, copied into RAM at startup (i.e. `.rodata` and
+	/// `.data`).
+	RamData,
+}
+
+/// A static of the firmware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+	/// The demangled name of the static.
+	///
+	/// For statics created by the `progmem!` macro, this is the name of the
+	/// `ProgMem` static, i.e. without the trailing `::VALUE`.
+	pub name: String,
+	/// The raw symbol name.
+	pub symbol: String,
+	/// The section of the static.
+	pub section: String,
+	/// The storage class of the static.
+	pub class: Class,
+	/// The address of the static.
+	///
+	/// In object files, this address is relative to the section, and for
+	/// RAM data, this is the address within the RAM.
+	pub address: u32,
+	/// The size of the static in bytes.
+	pub size: u32,
+}
+
+impl Item {
+	/// Returns the end address (exclusive) of this static.
+	pub fn end(&self) -> u32 {
+		self.address + self.size
+	}
+}
+
+/// All the statics of a firmware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+	/// Whether the addresses are final (i.e. for a linked firmware).
+	pub linked: bool,
+	/// All statics, sorted by address.
+	pub items: Vec<Item>,
+}
+
+impl Report {
+	/// Classify all statics of the given firmware (or object file).
+	///
+	/// In a linked firmware, the progmem data is merged into `.text`, thus
+	/// it is classified by its address: data in front of the code is
+	/// progmem (as the default `avr-ld` linker scripts put `.progmem.*`
+	/// first), data behind the code is far progmem (`.progmemx.*`).
+	/// In an object file, the section names are used instead.
+	pub fn new(elf: &Elf) -> Self {
+		let linked = elf.kind == FileKind::Executable;
+
+		// The start of the code in the flash
+		let code_start = elf
+			.symbols
+			.iter()
+			.filter(|sym| sym.kind == SymbolKind::Func && sym.value < DATA_OFFSET)
+			.map(|sym| sym.value)
+			.min()
+			.unwrap_or(u32::MAX);
+
+		let mut items: Vec<Item> = elf
+			.symbols
+			.iter()
+			.filter(|sym| sym.kind == SymbolKind::Object && sym.size > 0)
+			.filter_map(|sym| {
+				let section = elf.section_of(sym)?;
+
+				let (class, address) = if linked {
+					if section.flags & SHF_ALLOC == 0 {
+						return None;
+					} else if sym.value < DATA_OFFSET {
+						if sym.value < code_start {
+							(Class::Progmem, sym.value)
+						} else {
+							(Class::ProgmemFar, sym.value)
+						}
+					} else if sym.value < EEPROM_OFFSET && section.kind != SHT_NOBITS {
+						(Class::RamData, sym.value - DATA_OFFSET)
+					} else {
+						return None;
+					}
+				} else if section.name.starts_with(".progmemx") {
+					(Class::ProgmemFar, sym.value)
+				} else if section.name.starts_with(".progmem") {
+					(Class::Progmem, sym.value)
+				} else if section.name.starts_with(".rodata") {
+					(Class::RamData, sym.value)
+				} else {
+					return None;
+				};
+
+				let mut name = demangle(sym.name);
+				if class != Class::RamData && name.ends_with("::VALUE") {
+					name.truncate(name.len() - "::VALUE".len());
+				}
+
+				Some(Item {
+					name,
+					symbol: sym.name.to_string(),
+					section: section.name.to_string(),
+					class,
+					address,
+					size: sym.size,
+				})
+			})
+			.collect();
+
+		items.sort_by_key(|item| (item.address, item.section.clone()));
+
+		Report {
+			linked,
+			items,
+		}
+	}
+
+	/// Returns all statics of the given class.
+	pub fn of_class(&self, class: Class) -> impl Iterator<Item = &Item> {
+		self.items.iter().filter(move |item| item.class == class)
+	}
+
+	/// Returns the total size of all statics of the given class.
+	pub fn total_size(&self, class: Class) -> u32 {
+		self.of_class(class).map(|item| item.size).sum()
+	}
+
+	/// Returns the (non-far) progmem statics that end above 64 KiB, which
+	/// can not be read by `lpm` (their truncated 16-bit address points to
+	/// other data).
+	///
+	/// Always empty for object files, since their addresses are not final.
+	pub fn beyond_near_limit(&self) -> impl Iterator<Item = &Item> {
+		let linked = self.linked;
+
+		self.of_class(Class::Progmem)
+			.filter(move |item| linked && item.end() > NEAR_LIMIT)
+	}
+
+	/// Returns the initialized RAM data of at least `min_size` bytes, which
+	/// looks like it should have been put into progmem.
+	pub fn ram_candidates(&self, min_size: u32) -> impl Iterator<Item = &Item> {
+		self.of_class(Class::RamData)
+			.filter(move |item| item.size >= min_size)
+	}
+
+	/// Print the report in a human readable form.
+	pub fn print(&self, out: &mut impl Write, min_ram_size: u32) -> io::Result<()> {
+		let sections = [
+			(Class::Progmem, "Progmem statics (read via `lpm`):"),
+			(Class::ProgmemFar, "Far progmem statics (read via `elpm`):"),
+		];
+
+		for &(class, title) in sections.iter() {
+			if self.of_class(class).next().is_none() {
+				continue;
+			}
+
+			writeln!(out, "{}", title)?;
+			for item in self.of_class(class) {
+				print_item(out, item)?;
+			}
+			writeln!(out, "  total: {} bytes", self.total_size(class))?;
+			writeln!(out)?;
+		}
+
+		if self.beyond_near_limit().next().is_some() {
+			writeln!(
+				out,
+				"ERROR: progmem statics above 64 KiB, these can not be read via `lpm`, use the \
+				 `far` rule of `progmem!` or its \"elpm\" crate feature instead:"
+			)?;
+			for item in self.beyond_near_limit() {
+				print_item(out, item)?;
+			}
+			writeln!(out)?;
+		}
+
+		if self.ram_candidates(min_ram_size).next().is_some() {
+			if self.linked {
+				writeln!(
+					out,
+					"Initialized data in RAM (at least {} bytes), if it is read-only, consider \
+					 putting it into progmem:",
+					min_ram_size
+				)?;
+			} else {
+				writeln!(
+					out,
+					"Read-only data in RAM (at least {} bytes), consider putting it into progmem:",
+					min_ram_size
+				)?;
+			}
+			for item in self.ram_candidates(min_ram_size) {
+				print_item(out, item)?;
+			}
+			writeln!(out)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Print a single line of the report.
+fn print_item(out: &mut impl Write, item: &Item) -> io::Result<()> {
+	writeln!(
+		out,
+		"  0x{:06x} {:>6}  {}",
+		item.address, item.size, item.name
+	)
+}
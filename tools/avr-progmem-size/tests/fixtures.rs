@@ -0,0 +1,155 @@
+//! Tests against real AVR firmware, see `fixtures/README.md`.
+
+use std::fs;
+use std::path::Path;
+
+use avr_progmem_size::demangle::demangle;
+use avr_progmem_size::elf::Elf;
+use avr_progmem_size::elf::Error;
+use avr_progmem_size::elf::FileKind;
+use avr_progmem_size::report::Class;
+use avr_progmem_size::report::Report;
+use avr_progmem_size::report::DEFAULT_MIN_RAM_SIZE;
+
+
+fn fixture(name: &str) -> Vec<u8> {
+	let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("tests/fixtures")
+		.join(name);
+	fs::read(path).unwrap()
+}
+
+/// Returns the name, address, and size of the statics of the given class.
+fn statics(report: &Report, class: Class) -> Vec<(&str, u32, u32)> {
+	report
+		.of_class(class)
+		.map(|item| (item.name.as_str(), item.address, item.size))
+		.collect()
+}
+
+
+#[test]
+fn uno_firmware() {
+	let data = fixture("uno.elf");
+	let elf = Elf::parse(&data).unwrap();
+	assert_eq!(FileKind::Executable, elf.kind);
+
+	let report = Report::new(&elf);
+	assert!(report.linked);
+
+	assert_eq!(
+		vec![
+			("uno::GREETING", 0x00, 16),
+			("uno::BANNER", 0x10, 40),
+			("uno::SINE", 0x38, 64),
+		],
+		statics(&report, Class::Progmem)
+	);
+	assert_eq!(120, report.total_size(Class::Progmem));
+	assert_eq!(0, report.of_class(Class::ProgmemFar).count());
+	assert_eq!(0, report.beyond_near_limit().count());
+
+	// Only the large table, but neither the small one nor the `ProgMem`
+	// wrappers themselves.
+	let candidates: Vec<_> = report
+		.ram_candidates(DEFAULT_MIN_RAM_SIZE)
+		.map(|item| (item.name.as_str(), item.address, item.size))
+		.collect();
+	assert_eq!(vec![("uno::LOOKUP", 0x108, 128)], candidates);
+
+	assert!(report
+		.ram_candidates(1)
+		.any(|item| item.name == "uno::SMALL"));
+}
+
+#[test]
+fn uno_object_file() {
+	let data = fixture("uno.o");
+	let elf = Elf::parse(&data).unwrap();
+	assert_eq!(FileKind::Relocatable, elf.kind);
+
+	let report = Report::new(&elf);
+	assert!(!report.linked);
+
+	let names: Vec<_> = report
+		.of_class(Class::Progmem)
+		.map(|item| item.name.as_str())
+		.collect();
+	assert_eq!(vec!["uno::GREETING", "uno::BANNER", "uno::SINE"], names);
+	assert!(report
+		.of_class(Class::Progmem)
+		.all(|item| item.section.starts_with(".progmem.data")));
+
+	let candidates: Vec<_> = report
+		.ram_candidates(DEFAULT_MIN_RAM_SIZE)
+		.map(|item| (item.name.as_str(), item.size))
+		.collect();
+	assert_eq!(vec![("uno::LOOKUP", 128)], candidates);
+}
+
+#[test]
+fn mega_firmware_beyond_64k() {
+	let data = fixture("mega.elf");
+	let elf = Elf::parse(&data).unwrap();
+	let report = Report::new(&elf);
+
+	assert_eq!(
+		vec![
+			("mega::FILLER_A", 0x0000, 32760),
+			("mega::FILLER_B", 0x7ff8, 32760),
+			("mega::LATE", 0xfff0, 64),
+		],
+		statics(&report, Class::Progmem)
+	);
+
+	// The far data is placed behind the code
+	assert_eq!(
+		vec![("mega::FAR_TABLE", 0x10226, 32)],
+		statics(&report, Class::ProgmemFar)
+	);
+
+	// Only the near static crossing the limit is flagged, not the far one
+	let flagged: Vec<_> = report
+		.beyond_near_limit()
+		.map(|item| item.name.as_str())
+		.collect();
+	assert_eq!(vec!["mega::LATE"], flagged);
+}
+
+#[test]
+fn print_report() {
+	let data = fixture("mega.elf");
+	let report = Report::new(&Elf::parse(&data).unwrap());
+
+	let mut out = Vec::new();
+	report.print(&mut out, DEFAULT_MIN_RAM_SIZE).unwrap();
+	let out = String::from_utf8(out).unwrap();
+
+	assert!(out.contains("  0x00fff0     64  mega::LATE\n"));
+	assert!(out.contains("  0x010226     32  mega::FAR_TABLE\n"));
+	assert!(out.contains("ERROR: progmem statics above 64 KiB"));
+}
+
+#[test]
+fn invalid_files() {
+	assert_eq!(Some(Error::NotElf), Elf::parse(b"not an elf file").err());
+
+	// A truncated firmware
+	let data = fixture("uno.elf");
+	assert!(Elf::parse(&data[..1024]).is_err());
+}
+
+#[test]
+fn demangle_symbols() {
+	assert_eq!(
+		"uno::GREETING::VALUE",
+		demangle("_ZN3uno8GREETING5VALUE17hc6597b81ae5861afE")
+	);
+	assert_eq!(
+		"avr_progmem::wrapper::ProgMem<[T; N]>::load_at",
+		demangle(
+			"_ZN11avr_progmem7wrapper37ProgMem$LT$$u5b$T$u3b$$u20$N$u5d$$GT$7load_at17h9cb27bd69dd961dbE"
+		)
+	);
+	assert_eq!("main", demangle("main"));
+}
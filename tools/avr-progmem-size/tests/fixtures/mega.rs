@@ -0,0 +1,42 @@
+#![no_std]
+#![no_main]
+#![feature(asm_experimental_arch)]
+use avr_progmem::progmem;
+
+progmem! {
+	// Push the following progmem data beyond the 64 KiB limit
+	static progmem FILLER_A: [u8; 32760] = [0xAA; 32760];
+	static progmem FILLER_B: [u8; 32760] = [0xBB; 32760];
+	// Ends up (partially) above 64 KiB, but is read via `lpm`
+	static progmem LATE: [u8; 64] = [0x55; 64];
+	// Properly placed into the far progmem, read via `elpm`
+	static progmem far FAR_TABLE: [u8; 32] = [7; 32];
+}
+
+static mut COUNTER: u16 = 0;
+
+#[no_mangle]
+pub extern "C" fn main() -> ! {
+	let mut acc: u16 = 0;
+	loop {
+		let i = unsafe { core::ptr::read_volatile(core::ptr::addr_of!(COUNTER)) } as usize;
+		acc = acc.wrapping_add(FILLER_A.load_at(i & 1023) as u16);
+		acc = acc.wrapping_add(FILLER_B.load_at(i & 1023) as u16);
+		acc = acc.wrapping_add(LATE.load_at(i & 63) as u16);
+		acc = acc.wrapping_add(FAR_TABLE.load_at(i & 31) as u16);
+		unsafe {
+			core::ptr::write_volatile(core::ptr::addr_of_mut!(COUNTER), acc);
+		}
+	}
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+	loop {}
+}
+
+// Usually provided by the avr-libc
+#[no_mangle]
+pub extern "C" fn abort() -> ! {
+	loop {}
+}
@@ -0,0 +1,62 @@
+#![no_std]
+#![no_main]
+use avr_progmem::progmem;
+
+progmem! {
+	static progmem GREETING: [u8; 16] = *b"Hello progmem!\r\n";
+	static progmem string BANNER = "avr-progmem fixture firmware for the uno";
+	static progmem SINE: [u8; 64] = {
+		let mut a = [0u8; 64];
+		let mut i = 0;
+		while i < 64 {
+			a[i] = (i * 4) as u8;
+			i += 1;
+		}
+		a
+	};
+}
+
+// A large immutable table, which ends up in `.rodata`, i.e. in RAM on AVR
+static LOOKUP: [u16; 64] = {
+	let mut a = [0u16; 64];
+	let mut i = 0;
+	while i < 64 {
+		a[i] = (i * i) as u16;
+		i += 1;
+	}
+	a
+};
+
+// A small immutable table, below the default reporting threshold
+static SMALL: [u8; 4] = [1, 2, 4, 8];
+
+static mut COUNTER: u16 = 0;
+static mut BUFFER: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+#[no_mangle]
+pub extern "C" fn main() -> ! {
+	let mut acc: u16 = 0;
+	loop {
+		let i = unsafe { core::ptr::read_volatile(core::ptr::addr_of!(COUNTER)) } as usize;
+		acc = acc.wrapping_add(GREETING.load_at(i & 15) as u16);
+		acc = acc.wrapping_add(SINE.load_at(i & 63) as u16);
+		acc = acc.wrapping_add(BANNER.as_bytes().load_at(i & 31) as u16);
+		acc = acc.wrapping_add(core::hint::black_box(&LOOKUP)[i & 63]);
+		acc = acc.wrapping_add(core::hint::black_box(&SMALL)[i & 3] as u16);
+		unsafe {
+			core::ptr::write_volatile(core::ptr::addr_of_mut!(BUFFER[i & 7]), acc as u8);
+			core::ptr::write_volatile(core::ptr::addr_of_mut!(COUNTER), acc);
+		}
+	}
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+	loop {}
+}
+
+// Usually provided by the avr-libc
+#[no_mangle]
+pub extern "C" fn abort() -> ! {
+	loop {}
+}